@@ -0,0 +1,144 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::helpers::{Param, Params};
+
+/// Default location of the project configuration file.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/*
+Project configuration, loaded from `aoc.toml`. Every setting is optional and falls
+back to the defaults below, and the runner can override them from the command line.
+
+Per-day parameters live under `[params.yYYYY.dayN]`, and per-day timeouts (in seconds)
+under `[timeouts.yYYYY]`, e.g.
+
+    [params.y2023.day11]
+    expansion = 1000000
+
+    [timeouts.y2023]
+    day8 = 120
+*/
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub year: u32,
+    pub data_dir: PathBuf,
+    pub session_file: PathBuf,
+    pub output: OutputFormat,
+    /// Print how each answer was worked out, for solutions that can explain themselves.
+    pub explain: bool,
+    /// Seconds each part may run for, unless the day has its own timeout.
+    pub timeout: Option<f64>,
+    pub timeouts: HashMap<String, HashMap<String, f64>>,
+    pub params: HashMap<String, HashMap<String, Params>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            year: 2023,
+            data_dir: PathBuf::from("data/input"),
+            session_file: PathBuf::from(".session"),
+            output: OutputFormat::Text,
+            explain: false,
+            timeout: None,
+            timeouts: HashMap::new(),
+            params: HashMap::new(),
+        }
+    }
+}
+
+impl Config {
+    /// Loads the configuration from a TOML file.
+    pub fn load(path: &Path) -> Result<Config, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| format!("Could not read config file {}: {e}", path.display()))?;
+        Config::parse(&contents).map_err(|e| format!("Invalid config file {}: {e}", path.display()))
+    }
+
+    pub fn parse(contents: &str) -> Result<Config, String> {
+        let config: Config = toml::from_str(contents).map_err(|e| e.message().to_owned())?;
+
+        let timeouts = config.timeouts.values().flat_map(|days| days.values());
+        let mut timeouts = config.timeout.iter().chain(timeouts);
+        if let Some(seconds) = timeouts.find(|&&s| !is_valid_timeout(s)) {
+            return Err(format!(
+                "timeouts must be a positive number of seconds, and not too large, got {seconds}"
+            ));
+        }
+        Ok(config)
+    }
+
+    /// Parameters configured for a given day, empty if there are none.
+    pub fn params_for(&self, year: u32, day: u32) -> Params {
+        self.params
+            .get(&format!("y{year}"))
+            .and_then(|days| days.get(&format!("day{day}")))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /*
+    Parameters for a given day, resolved against the ones its solution declares.
+    The runner checks every day's parameters before running it, so they should be valid here.
+    */
+    pub fn resolved_params(&self, year: u32, day: u32, declared: &[Param]) -> Params {
+        Params::resolve(declared, &self.params_for(year, day))
+            .unwrap_or_else(|e| panic!("Invalid parameters for day {day}: {e}"))
+    }
+
+    /// How long each part of a given day may run for, if limited.
+    pub fn timeout_for(&self, year: u32, day: u32) -> Option<Duration> {
+        self.timeouts
+            .get(&format!("y{year}"))
+            .and_then(|days| days.get(&format!("day{day}")))
+            .or(self.timeout.as_ref())
+            .map(|&seconds| Duration::from_secs_f64(seconds))
+    }
+
+    /// Sets the timeout for every day, replacing any per-day timeouts.
+    pub fn set_timeout(&mut self, seconds: f64) {
+        self.timeout = Some(seconds);
+        self.timeouts.clear();
+    }
+
+    /// Sets a parameter for a given day, overriding any value from the config file.
+    pub fn set_param(&mut self, year: u32, day: u32, name: &str, value: &str) {
+        self.params
+            .entry(format!("y{year}"))
+            .or_default()
+            .entry(format!("day{day}"))
+            .or_default()
+            .set(name, value);
+    }
+}
+
+/// Whether a number of seconds can be used as a timeout: positive, and small enough for a `Duration`.
+pub fn is_valid_timeout(seconds: f64) -> bool {
+    seconds > 0. && Duration::try_from_secs_f64(seconds).is_ok()
+}
+
+impl std::str::FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(format!(
+                "Unknown output format '{s}', expected 'text' or 'json'"
+            )),
+        }
+    }
+}
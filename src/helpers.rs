@@ -1,33 +1,308 @@
-use textwrap::dedent;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use std::fmt;
+use std::panic;
+use std::str::FromStr;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
 
-pub(crate) struct Example {
+use crate::config::{Config, OutputFormat};
+
+#[derive(Default)]
+pub struct Example {
     pub input_data: String,
     pub expected_part1: u64,
+    /// Separate example input for part 2, for days where the puzzle gives a different one.
+    pub input_data_part2: Option<String>,
     pub expected_part2: u64,
+    /// Parameters the example is run with, for puzzles whose examples use different values.
+    pub params: &'static [(&'static str, &'static str)],
 }
 
-pub(crate) struct Solution {
+pub struct Solution {
     pub input_data: String,
-    pub get_solution_part1: fn(&str) -> u64,
-    pub get_solution_part2: fn(&str) -> u64,
+    pub get_solution_part1: fn(&str, &Params) -> u64,
+    pub get_solution_part2: fn(&str, &Params) -> u64,
+    /// Parameters the solution can be tuned with.
+    pub params: &'static [Param],
 }
 
 impl Solution {
-    pub fn print_solutions(self, day: u32, example: Example) {
-        // Example part 1
-        let example_solution_part1 = (self.get_solution_part1)(&example.input_data);
-        assert_eq!(example.expected_part1, example_solution_part1);
-
-        // Example part 2
-        let example_solution_part2 = (self.get_solution_part2)(&example.input_data);
-        assert_eq!(example.expected_part2, example_solution_part2);
-
-        // Part 1
-        let solution_part1 = (self.get_solution_part1)(&self.input_data);
-        println!("Day {day}, part 1: {solution_part1}");
-
-        // Part 2
-        let solution_part2 = (self.get_solution_part2)(&self.input_data);
-        println!("Day {day}, part 2: {solution_part2}");
+    pub fn print_solutions(self, year: u32, day: u32, example: Example, config: &Config) {
+        let example_params = Params::resolve(self.params, &Params::from(example.params))
+            .unwrap_or_else(|e| panic!("Invalid example parameters for day {day}: {e}"));
+        let params = config.resolved_params(year, day, self.params);
+        let timeout = config.timeout_for(year, day);
+
+        let example_input_part2 = example
+            .input_data_part2
+            .unwrap_or(example.input_data.clone());
+        let parts = [
+            (
+                1,
+                self.get_solution_part1,
+                example.input_data,
+                example.expected_part1,
+            ),
+            (
+                2,
+                self.get_solution_part2,
+                example_input_part2,
+                example.expected_part2,
+            ),
+        ];
+
+        for (part, get_solution, example_input, example_expected) in parts {
+            // Each part checks its example before running on the real input, both under the timeout
+            let input_data = self.input_data.clone();
+            let (example_params, params) = (example_params.clone(), params.clone());
+            let run_part = move || {
+                let example_solution = get_solution(&example_input, &example_params);
+                assert_eq!(example_expected, example_solution);
+                get_solution(&input_data, &params)
+            };
+
+            let solution = run_with_timeout(run_part, timeout);
+            print_answer(config.output, year, day, part, solution);
+        }
+    }
+}
+
+/*
+Runs a function, giving up once the timeout has passed.
+The function runs on its own thread, which is left running in the background if it times
+out since threads can't be cancelled. Panics are passed on to the caller.
+*/
+pub fn run_with_timeout<T, F>(f: F, timeout: Option<Duration>) -> Option<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let Some(timeout) = timeout else {
+        return Some(f());
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    let handle = thread::spawn(move || {
+        // Receiver is gone if we've already timed out
+        let _ = sender.send(f());
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => Some(result),
+        Err(RecvTimeoutError::Timeout) => None,
+        Err(RecvTimeoutError::Disconnected) => match handle.join() {
+            Err(panic) => panic::resume_unwind(panic),
+            Ok(()) => unreachable!("Thread should have sent a result before finishing"),
+        },
+    }
+}
+
+/// Prints the answer for a part, or TIMEOUT if it didn't finish in time.
+fn print_answer(output: OutputFormat, year: u32, day: u32, part: u32, answer: Option<u64>) {
+    match (output, answer) {
+        (OutputFormat::Text, Some(answer)) => println!("Day {day}, part {part}: {answer}"),
+        (OutputFormat::Text, None) => println!("Day {day}, part {part}: TIMEOUT"),
+        (OutputFormat::Json, _) => {
+            let status = if answer.is_some() { "ok" } else { "timeout" };
+            let record = json!({
+                "year": year,
+                "day": day,
+                "part": part,
+                "status": status,
+                "answer": answer,
+            });
+            println!("{record}");
+        }
+    }
+}
+
+/*
+A row of diagnostics a solution can give to explain how it got its answer, e.g. the tokens
+found on each line in 2023 day 1. Printed as a table, or in JSON as the serialized row.
+*/
+pub trait Explain: Serialize {
+    const COLUMNS: &'static [&'static str];
+
+    /// Values for the text table, one for each of the `COLUMNS`.
+    fn cells(&self) -> Vec<String>;
+}
+
+/// Prints the explanation for a part, as an aligned table or one JSON object per row.
+pub fn print_explanation<T: Explain>(
+    output: OutputFormat,
+    year: u32,
+    day: u32,
+    part: u32,
+    rows: &[T],
+) {
+    match output {
+        OutputFormat::Text => {
+            let header = T::COLUMNS.iter().map(|column| column.to_string()).collect();
+            let table: Vec<Vec<String>> = [header]
+                .into_iter()
+                .chain(rows.iter().map(Explain::cells))
+                .collect();
+            let widths: Vec<usize> = (0..T::COLUMNS.len())
+                .map(|i| {
+                    table
+                        .iter()
+                        .map(|row| row[i].chars().count())
+                        .max()
+                        .unwrap_or(0)
+                })
+                .collect();
+
+            println!("Day {day}, part {part} explained:");
+            for row in table {
+                let cells = row
+                    .iter()
+                    .zip(widths.iter())
+                    .map(|(cell, &width)| format!("{cell:<width$}"));
+                println!("  {}", cells.format("  ").to_string().trim_end());
+            }
+        }
+        OutputFormat::Json => {
+            for row in rows {
+                let record = json!({
+                    "year": year,
+                    "day": day,
+                    "part": part,
+                    "explain": row,
+                });
+                println!("{record}");
+            }
+        }
+    }
+}
+
+/// Prints a drawing a solution made to explain its answer, wrapped in a JSON object if needed.
+pub fn print_drawing(output: OutputFormat, year: u32, day: u32, drawing: &str) {
+    match output {
+        OutputFormat::Text => print!("{drawing}"),
+        OutputFormat::Json => {
+            let record = json!({
+                "year": year,
+                "day": day,
+                "drawing": drawing,
+            });
+            println!("{record}");
+        }
+    }
+}
+
+/*
+A named parameter a solution can be tuned with, e.g. the expansion factor in 2023 day 11.
+Values are checked with `parse` before the solution runs, which should accept exactly the
+values the solution can read the parameter as.
+*/
+#[derive(Debug, Clone, Copy)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: ParamValue,
+    pub description: &'static str,
+    pub parse: fn(&str) -> Result<(), String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParamValue {
+    Integer(i64),
+    Float(f64),
+    Text(&'static str),
+}
+
+/// Checks a parameter value can be read as `T`, for use as a `Param`'s `parse`.
+pub fn parses_as<T>(value: &str) -> Result<(), String>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    value.parse::<T>().map(|_| ()).map_err(|e| e.to_string())
+}
+
+impl fmt::Display for ParamValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamValue::Integer(value) => write!(f, "{value}"),
+            ParamValue::Float(value) => write!(f, "{value}"),
+            ParamValue::Text(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/*
+Values for a day's parameters, keyed by name.
+Values are kept as strings and parsed when a solution asks for them. Once resolved
+against a solution's declared parameters, every declared parameter has a valid value.
+*/
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(from = "HashMap<String, toml::Value>")]
+pub struct Params(HashMap<String, String>);
+
+impl Params {
+    /// Fills in defaults for the declared parameters and checks the overrides against them.
+    pub fn resolve(declared: &[Param], overrides: &Params) -> Result<Params, String> {
+        let mut params: HashMap<String, String> = declared
+            .iter()
+            .map(|param| (param.name.to_owned(), param.default.to_string()))
+            .collect();
+
+        for (name, value) in overrides.0.iter() {
+            let Some(param) = declared.iter().find(|param| param.name == name) else {
+                let names = declared.iter().map(|param| param.name).join(", ");
+                if names.is_empty() {
+                    return Err(format!("unknown parameter '{name}', none are available"));
+                }
+                return Err(format!(
+                    "unknown parameter '{name}', expected one of: {names}"
+                ));
+            };
+            (param.parse)(value)
+                .map_err(|e| format!("invalid value '{value}' for parameter '{name}': {e}"))?;
+            params.insert(name.to_owned(), value.to_owned());
+        }
+
+        Ok(Params(params))
+    }
+
+    /// Gets the value of a parameter, which must have been declared by the solution.
+    pub fn get<T: FromStr>(&self, name: &str) -> T {
+        let value = self
+            .0
+            .get(name)
+            .unwrap_or_else(|| panic!("Parameter '{name}' should have been declared"));
+        value
+            .parse()
+            .unwrap_or_else(|_| panic!("Invalid value '{value}' for parameter '{name}'"))
+    }
+
+    pub fn set(&mut self, name: &str, value: &str) {
+        self.0.insert(name.to_owned(), value.to_owned());
+    }
+}
+
+impl From<&[(&str, &str)]> for Params {
+    fn from(values: &[(&str, &str)]) -> Self {
+        let values = values
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        Params(values)
+    }
+}
+
+impl From<HashMap<String, toml::Value>> for Params {
+    fn from(values: HashMap<String, toml::Value>) -> Self {
+        let values = values
+            .into_iter()
+            .map(|(name, value)| match value {
+                toml::Value::String(value) => (name, value),
+                value => (name, value.to_string()),
+            })
+            .collect();
+        Params(values)
     }
 }
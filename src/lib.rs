@@ -0,0 +1,16 @@
+/*!
+Advent of Code solutions organised by year, along with the parsers and utilities they're built on.
+
+- [`solutions`]: solutions for each year and day, e.g. [`solutions::y2023::day2::parse_games`].
+- [`config`]: project configuration loaded from `aoc.toml`.
+- [`helpers`]: the [`helpers::Solution`] and [`helpers::Example`] types used to run and check a day.
+- [`utils`]: general purpose utilities shared between days, e.g. [`utils::diff()`].
+
+The `advent-of-code` binary is a thin runner on top of this library.
+*/
+#![allow(clippy::needless_return)]
+
+pub mod config;
+pub mod helpers;
+pub mod solutions;
+pub mod utils;
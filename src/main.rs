@@ -1,32 +1,129 @@
-pub mod helpers;
-pub mod solutions;
-pub mod utils;
-
-use solutions::day1::print_solutions_day1;
-use solutions::day10::print_solutions_day10;
-use solutions::day11::print_solutions_day11;
-use solutions::day12::print_solutions_day12;
-use solutions::day2::print_solutions_day2;
-use solutions::day3::print_solutions_day3;
-use solutions::day4::print_solutions_day4;
-use solutions::day5::print_solutions_day5;
-use solutions::day6::print_solutions_day6;
-use solutions::day7::print_solutions_day7;
-use solutions::day8::print_solutions_day8;
-use solutions::day9::print_solutions_day9;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::exit;
+use std::str::FromStr;
+
+use advent_of_code::config::{is_valid_timeout, Config, CONFIG_FILE};
+use advent_of_code::helpers::Params;
+use advent_of_code::solutions::{self, get_days};
+
+const USAGE: &str = "Usage: advent-of-code [--year YEAR] [--day DAY] [--config FILE] \
+[--data-dir DIR] [--session-file FILE] [--output text|json] [--explain] [--timeout SECONDS] [--param NAME=VALUE]...";
 
 fn main() {
-    // TODO: how to run a single day?
-    // print_solutions_day1();
-    // print_solutions_day2();
-    // print_solutions_day3();
-    // print_solutions_day4();
-    // print_solutions_day5();
-    // print_solutions_day6();
-    // print_solutions_day7();
-    // print_solutions_day8();
-    print_solutions_day9();
-    print_solutions_day10();
-    print_solutions_day11();
-    print_solutions_day12();
+    let mut args = env::args().skip(1).collect::<Vec<_>>();
+
+    // The config file needs to be loaded before applying other overrides
+    let config_path = take_option(&mut args, "--config").map(PathBuf::from);
+    let mut config = load_config(config_path.as_deref());
+
+    let mut day: Option<u32> = None;
+    let mut params: Vec<(String, String)> = Vec::new();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" | "-y" => config.year = parse_arg_value(&arg, args.next()),
+            "--day" | "-d" => day = Some(parse_arg_value(&arg, args.next())),
+            "--data-dir" => config.data_dir = parse_arg_value(&arg, args.next()),
+            "--session-file" => config.session_file = parse_arg_value(&arg, args.next()),
+            "--output" | "-o" => config.output = parse_arg_value(&arg, args.next()),
+            "--explain" | "-e" => config.explain = true,
+            "--timeout" | "-t" => {
+                let seconds: f64 = parse_arg_value(&arg, args.next());
+                if !is_valid_timeout(seconds) {
+                    exit_with_usage(
+                        "Timeout must be a positive number of seconds, and not too large",
+                    );
+                }
+                config.set_timeout(seconds);
+            }
+            "--param" | "-p" => params.push(parse_param(args.next())),
+            "--help" | "-h" => {
+                println!("{USAGE}");
+                return;
+            }
+            _ => exit_with_usage(&format!("Unexpected argument '{arg}'")),
+        }
+    }
+
+    let year = config.year;
+    if !params.is_empty() {
+        let Some(day) = day else {
+            exit_with_usage("Parameters can only be set when running a single --day")
+        };
+        for (name, value) in params.iter() {
+            config.set_param(year, day, name, value);
+        }
+    }
+
+    let days = get_days(year).unwrap_or_else(|| {
+        let years = solutions::YEARS.iter().map(|(y, _)| y.to_string());
+        exit_with_usage(&format!(
+            "No solutions for year {year}, available years: {}",
+            years.collect::<Vec<_>>().join(", ")
+        ))
+    });
+
+    let selected = days
+        .iter()
+        .filter(|(d, _, _)| day.is_none_or(|target| *d == target))
+        .collect::<Vec<_>>();
+    if selected.is_empty() {
+        exit_with_usage(&format!("No solution for {year} day {}", day.unwrap_or(0)));
+    }
+
+    // Check every selected day's parameters before running anything
+    for (d, _, declared) in selected.iter() {
+        if let Err(e) = Params::resolve(declared, &config.params_for(year, *d)) {
+            exit_with_usage(&format!("Invalid parameters for {year} day {d}: {e}"));
+        }
+    }
+
+    for (_, print_solutions, _) in selected {
+        print_solutions(&config);
+    }
+}
+
+/*
+Loads the config from the given path, or from `aoc.toml` if it exists.
+Falls back to the default config when no file is given or found.
+*/
+fn load_config(path: Option<&Path>) -> Config {
+    let default_path = Path::new(CONFIG_FILE);
+    let path = match path {
+        Some(path) => path,
+        None if default_path.exists() => default_path,
+        None => return Config::default(),
+    };
+    Config::load(path).unwrap_or_else(|e| exit_with_usage(&e))
+}
+
+/// Removes an option and its value from the arguments, returning the value.
+fn take_option(args: &mut Vec<String>, flag: &str) -> Option<String> {
+    let idx = args.iter().position(|arg| arg == flag)?;
+    args.remove(idx);
+    if idx < args.len() {
+        Some(args.remove(idx))
+    } else {
+        exit_with_usage(&format!("Expected a value after '{flag}'"))
+    }
+}
+
+fn parse_arg_value<T: FromStr>(flag: &str, value: Option<String>) -> T {
+    value
+        .and_then(|x| x.parse().ok())
+        .unwrap_or_else(|| exit_with_usage(&format!("Expected a valid value after '{flag}'")))
+}
+
+fn parse_param(value: Option<String>) -> (String, String) {
+    value
+        .as_deref()
+        .and_then(|x| x.split_once('='))
+        .map(|(name, value)| (name.trim().to_owned(), value.trim().to_owned()))
+        .unwrap_or_else(|| exit_with_usage("Expected NAME=VALUE after '--param'"))
+}
+
+fn exit_with_usage(message: &str) -> ! {
+    eprintln!("{message}\n{USAGE}");
+    exit(2);
 }
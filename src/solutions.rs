@@ -1,12 +1,22 @@
-pub mod day1;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day2;
-pub mod day3;
-pub mod day4;
-pub mod day5;
-pub mod day6;
-pub mod day7;
-pub mod day8;
-pub mod day9;
+pub mod y2023;
+
+use crate::config::Config;
+use crate::helpers::Param;
+
+/// Runs a day and prints its solutions.
+pub type PrintSolutions = fn(&Config);
+
+/// Registered days for a year, each mapped to the function printing its solutions and the
+/// parameters it declares.
+pub type Days = &'static [(u32, PrintSolutions, &'static [Param])];
+
+/// Years with solutions available, each mapped to its days.
+pub const YEARS: [(u32, Days); 1] = [(y2023::YEAR, &y2023::DAYS)];
+
+/// Looks up the registered days for a given year.
+pub fn get_days(year: u32) -> Option<Days> {
+    YEARS
+        .iter()
+        .find(|(y, _)| *y == year)
+        .map(|(_, days)| *days)
+}
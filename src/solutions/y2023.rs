@@ -0,0 +1,33 @@
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+use super::PrintSolutions;
+use crate::helpers::Param;
+
+pub const YEAR: u32 = 2023;
+
+/// Days solved for this year, in the order they're run.
+pub const DAYS: [(u32, PrintSolutions, &[Param]); 12] = [
+    (1, day1::print_solutions_day1, &day1::PARAMS),
+    (2, day2::print_solutions_day2, &day2::PARAMS),
+    (3, day3::print_solutions_day3, &day3::PARAMS),
+    (4, day4::print_solutions_day4, &[]),
+    (5, day5::print_solutions_day5, &[]),
+    (6, day6::print_solutions_day6, &day6::PARAMS),
+    (7, day7::print_solutions_day7, &[]),
+    (8, day8::print_solutions_day8, &day8::PARAMS),
+    (9, day9::print_solutions_day9, &day9::PARAMS),
+    (10, day10::print_solutions_day10, &[]),
+    (11, day11::print_solutions_day11, &day11::PARAMS),
+    (12, day12::print_solutions_day12, &[]),
+];
@@ -0,0 +1,286 @@
+use serde::Serialize;
+use std::str::FromStr;
+use textwrap::dedent;
+
+use super::YEAR;
+use crate::config::Config;
+use crate::helpers::{
+    parses_as, print_explanation, Example, Explain, Param, ParamValue, Params, Solution,
+};
+use crate::utils::parse::ParseError;
+use crate::utils::{find_first, find_last, get_input_for_day, Match};
+
+pub const PARAMS: [Param; 1] = [Param {
+    name: "vocabulary",
+    default: ParamValue::Text("numerals,english"),
+    description: "Tokens read as digits in part 2: comma separated sets (numerals, english, \
+                  german, french, spanish) or custom 'token=digit' pairs, e.g. 'english,zero=0'",
+    parse: parses_as::<Vocabulary>,
+}];
+
+/// Built in sets of tokens, each listing the tokens for 0 to 9.
+const LANGUAGES: [(&str, [&str; 10]); 5] = [
+    (
+        "numerals",
+        ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"],
+    ),
+    (
+        "english",
+        [
+            "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ],
+    ),
+    (
+        "german",
+        [
+            "null", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+        ],
+    ),
+    (
+        "french",
+        [
+            "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+        ],
+    ),
+    (
+        "spanish",
+        [
+            "cero", "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
+        ],
+    ),
+];
+
+/*
+Tokens that are read as digits, along with the digit each one stands for.
+The spelled out sets leave out the word for zero, as the puzzle does, so it has to be asked
+for separately. Numerals keep "0", as a 0 in the input is still a digit.
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Vocabulary {
+    tokens: Vec<String>,
+    digits: Vec<u32>,
+}
+
+/// How the calibration value of a line was worked out, `None` where it has no digits.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Calibration {
+    /// Line number in the input, counting from 1.
+    pub line: usize,
+    pub text: String,
+    pub first: Option<Token>,
+    pub last: Option<Token>,
+    pub value: Option<u32>,
+}
+
+/// Token read as a digit, and the byte position in the line where it starts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Token {
+    pub token: String,
+    pub position: usize,
+    pub digit: u32,
+}
+
+pub fn print_solutions_day1(config: &Config) {
+    let day: u32 = 1;
+    let example = Example {
+        input_data: dedent(
+            "
+            1abc2
+            pqr3stu8vwx
+            a1b2c3d4e5f
+            treb7uchet
+        ",
+        ),
+        expected_part1: 142,
+        input_data_part2: Some(dedent(
+            "
+            two1nine
+            eightwothree
+            abcone2threexyz
+            xtwone3four
+            4nineeightseven2
+            zoneight234
+            7pqrstsixteen
+        ",
+        )),
+        expected_part2: 281,
+        ..Default::default()
+    };
+    let input_data = get_input_for_day(&config.data_dir, YEAR, day);
+    let solution = Solution {
+        input_data: input_data.clone(),
+        get_solution_part1: get_solution_day1_part1,
+        get_solution_part2: get_solution_day1_part2,
+        params: &PARAMS,
+    };
+    solution.print_solutions(YEAR, day, example, config);
+
+    if config.explain {
+        let params = config.resolved_params(YEAR, day, &PARAMS);
+        let vocabularies = [
+            Vocabulary::language("numerals").unwrap(),
+            params.get("vocabulary"),
+        ];
+        for (part, vocabulary) in (1..).zip(vocabularies.iter()) {
+            let calibrations = explain_calibrations(&input_data, vocabulary);
+            print_explanation(config.output, YEAR, day, part, &calibrations);
+        }
+    }
+}
+
+pub fn get_solution_day1_part2(input_data: &str, params: &Params) -> u64 {
+    let vocabulary: Vocabulary = params.get("vocabulary");
+    return get_solution(input_data, &vocabulary);
+}
+
+pub fn get_solution_day1_part1(input_data: &str, _params: &Params) -> u64 {
+    return get_solution(input_data, &Vocabulary::language("numerals").unwrap());
+}
+
+pub fn get_solution(input_data: &str, vocabulary: &Vocabulary) -> u64 {
+    // Lines without any digits don't have a calibration value
+    let cal_vals: Vec<u32> = input_data
+        .lines()
+        .filter_map(|line| get_calibration_value(line, vocabulary))
+        .collect();
+    let solution: u32 = cal_vals.iter().sum();
+    return solution as u64;
+}
+
+/*
+Finds the first and last digits in a string and uses them to create a 2-digit number
+{first}{last} e.g. '1abc2' -> 12, or `None` if there are no digits.
+Scans in from each end, stopping at the first token found, so tokens can overlap,
+e.g. 'eightwo' -> 82 when reading english words.
+*/
+pub fn get_calibration_value(line: &str, vocabulary: &Vocabulary) -> Option<u32> {
+    let first = find_first(line, &vocabulary.tokens)?;
+    let last = find_last(line, &vocabulary.tokens)?;
+
+    let cal_val: u32 = 10 * vocabulary.digits[first.pattern] + vocabulary.digits[last.pattern];
+    return Some(cal_val);
+}
+
+/// Which tokens gave each line's calibration value, including lines without any digits.
+pub fn explain_calibrations(input_data: &str, vocabulary: &Vocabulary) -> Vec<Calibration> {
+    let token = |found: Match| Token {
+        token: found.token.to_owned(),
+        position: found.position,
+        digit: vocabulary.digits[found.pattern],
+    };
+
+    let mut calibrations: Vec<Calibration> = vec![];
+    for (i, line) in input_data.lines().enumerate() {
+        let first = find_first(line, &vocabulary.tokens).map(token);
+        let last = find_last(line, &vocabulary.tokens).map(token);
+        let value = first
+            .as_ref()
+            .zip(last.as_ref())
+            .map(|(first, last)| 10 * first.digit + last.digit);
+        calibrations.push(Calibration {
+            line: i + 1,
+            text: line.to_owned(),
+            first,
+            last,
+            value,
+        });
+    }
+    return calibrations;
+}
+
+impl Vocabulary {
+    /// One of the built in sets of tokens, without the word for zero unless it's numerals.
+    pub fn language(name: &str) -> Option<Vocabulary> {
+        let (_, tokens) = LANGUAGES.iter().find(|(language, _)| *language == name)?;
+        let mut vocabulary = Vocabulary::default();
+        for (digit, token) in tokens.iter().enumerate() {
+            if digit > 0 || name == "numerals" {
+                vocabulary.insert(token, digit as u32);
+            }
+        }
+        Some(vocabulary)
+    }
+
+    /// Adds a token, replacing the digit it stands for if it's already in the vocabulary.
+    pub fn insert(&mut self, token: &str, digit: u32) {
+        assert!(
+            digit < 10,
+            "Tokens must stand for a single digit, got {digit}"
+        );
+        match self.tokens.iter().position(|existing| existing == token) {
+            Some(i) => self.digits[i] = digit,
+            None => {
+                self.tokens.push(token.to_owned());
+                self.digits.push(digit);
+            }
+        }
+    }
+
+    /// Combines the tokens of both vocabularies, with `other` taking precedence.
+    pub fn extend(&mut self, other: &Vocabulary) {
+        for (token, &digit) in other.tokens.iter().zip(other.digits.iter()) {
+            self.insert(token, digit);
+        }
+    }
+
+    pub fn tokens(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.tokens
+            .iter()
+            .map(String::as_str)
+            .zip(self.digits.iter().copied())
+    }
+}
+
+impl FromStr for Vocabulary {
+    type Err = ParseError;
+
+    // E.g. "numerals,english,zero=0"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut vocabulary = Vocabulary::default();
+        for entry in s
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+        {
+            if let Some((token, digit)) = entry.split_once('=') {
+                let digit: u32 = digit
+                    .trim()
+                    .parse()
+                    .ok()
+                    .filter(|&digit| digit < 10)
+                    .ok_or_else(|| ParseError::new(format!("Invalid digit in '{entry}'")))?;
+                if token.trim().is_empty() {
+                    return Err(ParseError::new(format!("Missing token in '{entry}'")));
+                }
+                vocabulary.insert(token.trim(), digit);
+            } else {
+                let language = Vocabulary::language(entry)
+                    .ok_or_else(|| ParseError::new(format!("Unknown vocabulary '{entry}'")))?;
+                vocabulary.extend(&language);
+            }
+        }
+        Ok(vocabulary)
+    }
+}
+
+impl Explain for Calibration {
+    const COLUMNS: &'static [&'static str] = &["line", "text", "first", "last", "value"];
+
+    fn cells(&self) -> Vec<String> {
+        let token = |token: &Option<Token>| match token {
+            Some(token) => format!("{} @ {}", token.token, token.position),
+            None => "-".to_owned(),
+        };
+        let value = match self.value {
+            Some(value) => value.to_string(),
+            None => "no digits".to_owned(),
+        };
+        vec![
+            self.line.to_string(),
+            self.text.clone(),
+            token(&self.first),
+            token(&self.last),
+            value,
+        ]
+    }
+}
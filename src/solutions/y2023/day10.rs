@@ -0,0 +1,161 @@
+use textwrap::dedent;
+
+use super::YEAR;
+use crate::config::Config;
+use crate::helpers::{Example, Params, Solution};
+use crate::utils::{get_input_for_day, Direction, Grid, Point};
+
+pub fn print_solutions_day10(config: &Config) {
+    let day: u32 = 10;
+    let example = Example {
+        input_data: dedent(
+            "
+            .F----7F7F7F7F-7....
+            .|F--7||||||||FJ....
+            .||.FJ||||||||L7....
+            FJL7L7LJLJ||LJ.L-7..
+            L--J.L7...LJS7F-7L7.
+            ....F-J..F7FJ|L7L7L7
+            ....L7.F7||L7|.L7L7|
+            .....|FJLJ|FJ|F7|.LJ
+            ....FJL-7.||.||||...
+            ....L---J.LJ.LJLJ...
+        ",
+        ),
+        expected_part1: 70,
+        expected_part2: 8,
+        ..Default::default()
+    };
+    let solution = Solution {
+        input_data: get_input_for_day(&config.data_dir, YEAR, day),
+        get_solution_part1,
+        get_solution_part2,
+        params: &[],
+    };
+    solution.print_solutions(YEAR, day, example, config);
+}
+
+pub fn get_solution_part1(input_data_raw: &str, _params: &Params) -> u64 {
+    let board = get_board(input_data_raw);
+    let forward_path = get_loop_path(&board);
+    (forward_path.nodes.len() / 2) as u64
+}
+
+/*
+First time I've had to use reddit for help this year!
+Thought I'd have to implement a flood fill but didn't want to do that so
+checked for a better way! Glad I did otherwise I wouldn't have finished
+this day.
+*/
+pub fn get_solution_part2(input_data_raw: &str, _params: &Params) -> u64 {
+    let board: Grid<char> = get_board(input_data_raw);
+    let forward_path = get_loop_path(&board);
+
+    // https://en.wikipedia.org/wiki/Pick%27s_theorem
+    let mut area: f64 = 0.;
+    for (p1, p2) in forward_path
+        .nodes
+        .iter()
+        .zip(forward_path.nodes.iter().skip(1))
+    {
+        area += determinant(p1.row, p2.row, p1.col, p2.col);
+    }
+    // Area is negative if the loop was walked clockwise
+    area = area.abs() / 2.;
+
+    // https://en.wikipedia.org/wiki/Shoelace_formula#Example
+    // Minus 1 to boundary because start appears twice.
+    let num_boundary = forward_path.nodes.len() as f64 - 1.;
+    let num_enclosed = area - num_boundary / 2. + 1.;
+
+    num_enclosed as u64
+}
+
+fn determinant(a: usize, b: usize, c: usize, d: usize) -> f64 {
+    ((a * d) as f64) - ((b * c) as f64)
+}
+
+pub fn get_board(input_data_raw: &str) -> Grid<char> {
+    input_data_raw.parse().unwrap()
+}
+
+/*
+Follows the pipes from each neighbour of the start until they lead back round to it.
+Every other tile on the loop connects to exactly two others, but stray pipes can also
+point at the start, so a walk that starts down one of those hits a dead end instead.
+The path ends back at the start to close the loop.
+*/
+pub fn get_loop_path(board: &Grid<char>) -> Path {
+    let start = Point::from(board.position(|&c| c == 'S').unwrap());
+
+    for first in get_transitions(start, &'S', board) {
+        let mut nodes = vec![start];
+        let (mut previous, mut current) = (start, first);
+        while current != start {
+            nodes.push(current);
+            let next = get_transitions(current, &board[current], board)
+                .into_iter()
+                .find(|&point| point != previous);
+            let Some(next) = next else {
+                break;
+            };
+            (previous, current) = (current, next);
+        }
+        if current == start {
+            nodes.push(start);
+            return Path { nodes };
+        }
+    }
+    panic!("No loop of pipes leads back to the start at {start}");
+}
+
+fn get_transitions(point: Point, c: &char, board: &Grid<char>) -> Vec<Point> {
+    let mut transitions: Vec<Point> = Vec::new();
+
+    for direction in Direction::ALL {
+        // Target coords, skipping any that fall off the board
+        let Some(next_point) = point.step(direction) else {
+            continue;
+        };
+
+        // Check character
+        let c2 = board.get(next_point.row, next_point.col).unwrap_or(&'X');
+        if validate_transition(c, c2, direction) {
+            transitions.insert(0, next_point)
+        }
+    }
+
+    return transitions;
+}
+
+fn validate_transition(c1: &char, c2: &char, direction: Direction) -> bool {
+    // From -> to
+    let downs = ['|', '7', 'F'];
+    let ups = ['|', 'L', 'J'];
+    let lefts = ['-', 'J', '7'];
+    let rights = ['-', 'L', 'F'];
+
+    match direction {
+        Direction::Up => {
+            ((ups.contains(c1) || c1 == &'S') && downs.contains(c2))
+                || ((ups.contains(c1)) && (downs.contains(c2) || c2 == &'S'))
+        }
+        Direction::Down => {
+            ((downs.contains(c1) || c1 == &'S') && ups.contains(c2))
+                || (downs.contains(c1) && (ups.contains(c2) || c2 == &'S'))
+        }
+        Direction::Left => {
+            ((lefts.contains(c1) || c1 == &'S') && rights.contains(c2))
+                || (lefts.contains(c1) && (rights.contains(c2) || c2 == &'S'))
+        }
+        Direction::Right => {
+            ((rights.contains(c1) || c1 == &'S') && lefts.contains(c2))
+                || (rights.contains(c1) && (lefts.contains(c2) || c2 == &'S'))
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Path {
+    pub nodes: Vec<Point>,
+}
@@ -0,0 +1,115 @@
+use hashbrown::HashMap;
+use itertools::Itertools;
+use textwrap::dedent;
+
+use super::YEAR;
+use crate::config::Config;
+use crate::helpers::{Example, Param, ParamValue, Params, Solution};
+use crate::utils::{get_input_for_day, Grid, Point};
+
+pub const PARAMS: [Param; 1] = [Param {
+    name: "expansion",
+    default: ParamValue::Integer(1000000),
+    description: "Size each empty row and column expands to in part 2",
+    parse: parse_expansion,
+}];
+
+/// Checks the expansion is a size an empty row or column can take, which is at least 1.
+fn parse_expansion(value: &str) -> Result<(), String> {
+    match value.parse::<usize>() {
+        Ok(0) => Err("expansion must be at least 1".to_owned()),
+        Ok(_) => Ok(()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+pub fn print_solutions_day11(config: &Config) {
+    let day: u32 = 11;
+    let example = Example {
+        input_data: dedent(
+            "
+            ...#......
+            .......#..
+            #.........
+            ..........
+            ......#...
+            .#........
+            .........#
+            ..........
+            .......#..
+            #...#.....
+        ",
+        ),
+        expected_part1: 374,
+        expected_part2: 8410,
+        params: &[("expansion", "100")],
+        ..Default::default()
+    };
+    let solution = Solution {
+        input_data: get_input_for_day(&config.data_dir, YEAR, day),
+        get_solution_part1,
+        get_solution_part2,
+        params: &PARAMS,
+    };
+    solution.print_solutions(YEAR, day, example, config);
+}
+
+pub fn get_solution_part1(input_data_raw: &str, _params: &Params) -> u64 {
+    let coords = get_expanded_coords(input_data_raw, None);
+    get_total_distance(&coords)
+}
+
+pub fn get_solution_part2(input_data_raw: &str, params: &Params) -> u64 {
+    let coords = get_expanded_coords(input_data_raw, Some(params.get("expansion")));
+    get_total_distance(&coords)
+}
+
+pub fn get_expanded_coords(
+    input_data_raw: &str,
+    offset_size: Option<usize>,
+) -> HashMap<(usize, usize), char> {
+    let image: Grid<char> = input_data_raw.parse().unwrap();
+    let (expand_rows, expand_cols) = get_expand_indices(&image);
+    let mut coords: HashMap<(usize, usize), char> = HashMap::new();
+
+    // Get modified coordinates of #'s
+    for (i, j) in image.find_all(|&c| c == '#') {
+        let i_offset = expand_rows.iter().filter(|&i2| i2 < &i).collect_vec().len();
+        let i_expanded = i + i_offset * (offset_size.unwrap_or(2) - 1);
+
+        let j_offset = expand_cols.iter().filter(|&j2| j2 < &j).collect_vec().len();
+        let j_expanded = j + j_offset * (offset_size.unwrap_or(2) - 1);
+
+        coords.insert((i_expanded, j_expanded), '#');
+    }
+    return coords;
+}
+
+pub fn get_total_distance(coords: &HashMap<(usize, usize), char>) -> u64 {
+    let mut distance = 0;
+
+    for pair in coords.keys().combinations(2) {
+        let p1 = Point::from(*pair[0]);
+        let p2 = Point::from(*pair[1]);
+        distance += p1.manhattan(p2);
+    }
+
+    distance as u64
+}
+
+/// Indexes of the rows and columns that are empty, and so need to be expanded.
+pub fn get_expand_indices(image: &Grid<char>) -> (Vec<usize>, Vec<usize>) {
+    let is_empty = |&c: &char| c == '.';
+
+    let expand_rows: Vec<usize> = image
+        .rows()
+        .positions(|row| row.iter().all(is_empty))
+        .collect();
+
+    let expand_cols: Vec<usize> = image
+        .cols()
+        .positions(|mut col| col.all(is_empty))
+        .collect();
+
+    return (expand_rows, expand_cols);
+}
@@ -0,0 +1,170 @@
+use itertools::Itertools;
+use serde::Serialize;
+use std::iter;
+use textwrap::dedent;
+
+use super::YEAR;
+use crate::config::Config;
+use crate::helpers::{print_explanation, Example, Explain, Params, Solution};
+use crate::utils::get_input_for_day;
+use crate::utils::memo::{CacheStats, Memo};
+use crate::utils::parse::{ints, ParseError};
+
+pub fn print_solutions_day12(config: &Config) {
+    let day: u32 = 12;
+    let example = Example {
+        input_data: dedent(
+            "
+            ???.### 1,1,3
+            .??..??...?##. 1,1,3
+            ?#?#?#?#?#?#?#? 1,3,1,6
+            ????.#...#... 4,1,1
+            ????.######..#####. 1,6,5
+            ?###???????? 3,2,1
+        ",
+        ),
+        expected_part1: 21,
+        expected_part2: 525152,
+        ..Default::default()
+    };
+    let input_data = get_input_for_day(&config.data_dir, YEAR, day);
+    let solution = Solution {
+        input_data: input_data.clone(),
+        get_solution_part1,
+        get_solution_part2,
+        params: &[],
+    };
+    solution.print_solutions(YEAR, day, example, config);
+
+    // The cache is only counted in debug builds, so there's nothing to show otherwise
+    if config.explain && cfg!(debug_assertions) {
+        for (part, copies) in [(1, 1), (2, 5)] {
+            let rows = explain_arrangements(&input_data, copies);
+            print_explanation(config.output, YEAR, day, part, &rows);
+        }
+    }
+}
+
+pub fn get_solution_part1(input_data_raw: &str, _params: &Params) -> u64 {
+    get_solution(input_data_raw, 1)
+}
+
+pub fn get_solution_part2(input_data_raw: &str, _params: &Params) -> u64 {
+    get_solution(input_data_raw, 5)
+}
+
+pub fn get_solution(input_data_raw: &str, copies: usize) -> u64 {
+    input_data_raw
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| parse_row(line).unwrap())
+        .map(|(springs, groups)| {
+            let springs = iter::repeat_n(springs, copies).join("?");
+            let groups = groups.repeat(copies);
+            count_arrangements(springs.as_bytes(), &groups)
+        })
+        .sum()
+}
+
+/// Arrangements for a row, and how often the memo cache was used to count them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ArrangementStats {
+    /// Line number in the input, counting from 1.
+    pub line: usize,
+    pub arrangements: u64,
+    pub hits: u64,
+    pub misses: u64,
+}
+
+/// Arrangements and cache use for each row, which are only counted in debug builds.
+pub fn explain_arrangements(input_data_raw: &str, copies: usize) -> Vec<ArrangementStats> {
+    input_data_raw
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| {
+            let (springs, groups) = parse_row(line).unwrap();
+            let springs = iter::repeat_n(springs, copies).join("?");
+            let groups = groups.repeat(copies);
+            let (arrangements, stats) = count_arrangements_with_stats(springs.as_bytes(), &groups);
+            let stats = stats.unwrap_or_default();
+            ArrangementStats {
+                line: idx + 1,
+                arrangements,
+                hits: stats.hits,
+                misses: stats.misses,
+            }
+        })
+        .collect()
+}
+
+/// Splits a row like `???.### 1,1,3` into its springs and the sizes of its damaged groups.
+pub fn parse_row(line: &str) -> Result<(&str, Vec<usize>), ParseError> {
+    let (springs, groups) = line
+        .split_whitespace()
+        .collect_tuple()
+        .ok_or_else(|| ParseError::new(format!("Expected 'springs groups' but found '{line}'")))?;
+    if let Some(c) = springs.chars().find(|c| !".#?".contains(*c)) {
+        return Err(ParseError::new(format!(
+            "Invalid spring '{c}' in '{springs}'"
+        )));
+    }
+    Ok((springs, ints(groups)?))
+}
+
+/*
+Number of ways to fill in the unknown springs (`?`) so the runs of damaged springs (`#`)
+match the groups in order.
+Works through the row a spring at a time, where the state is the position in the row and
+the number of groups already placed. At each spring either it's operational and we move
+on, or the next group starts there, which needs the next few springs to be damaged and
+the one after to be operational (or the end of the row).
+*/
+pub fn count_arrangements(springs: &[u8], groups: &[usize]) -> u64 {
+    count_arrangements_with_stats(springs, groups).0
+}
+
+/// Same as `count_arrangements`, along with the memo's cache stats in debug builds.
+pub fn count_arrangements_with_stats(
+    springs: &[u8],
+    groups: &[usize],
+) -> (u64, Option<CacheStats>) {
+    let mut count = Memo::dense(
+        [springs.len() + 1, groups.len() + 1],
+        |count, [position, placed]| {
+            let Some(&spring) = springs.get(position) else {
+                return (placed == groups.len()) as u64;
+            };
+
+            let mut total = 0;
+            if spring != b'#' {
+                total += count([position + 1, placed]);
+            }
+            if spring != b'.' && placed < groups.len() {
+                let end = position + groups[placed];
+                let fits = end <= springs.len()
+                    && !springs[position..end].contains(&b'.')
+                    && springs.get(end) != Some(&b'#');
+                if fits {
+                    total += count([(end + 1).min(springs.len()), placed + 1]);
+                }
+            }
+            total
+        },
+    );
+    let arrangements = count.get([0, 0]);
+    (arrangements, count.stats())
+}
+
+impl Explain for ArrangementStats {
+    const COLUMNS: &'static [&'static str] = &["line", "arrangements", "hits", "misses"];
+
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.line.to_string(),
+            self.arrangements.to_string(),
+            self.hits.to_string(),
+            self.misses.to_string(),
+        ]
+    }
+}
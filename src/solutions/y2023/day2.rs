@@ -0,0 +1,338 @@
+use itertools::Itertools;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+use textwrap::dedent;
+
+use super::YEAR;
+use crate::config::Config;
+use crate::helpers::{parses_as, Example, Param, ParamValue, Params, Solution};
+use crate::utils::get_input_for_day;
+use crate::utils::parse::{key_values, ParseError};
+
+pub const PARAMS: [Param; 1] = [Param {
+    name: "bag",
+    default: ParamValue::Text("12 red, 13 green, 14 blue"),
+    description: "Cubes of each colour in the bag, e.g. '12 red, 13 green, 14 blue'",
+    parse: parses_as::<Bag>,
+}];
+
+pub fn print_solutions_day2(config: &Config) {
+    let day: u32 = 2;
+    let example = Example {
+        input_data: dedent(
+            "
+            Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+            Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+            Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+            Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+            Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+        ",
+        ),
+        expected_part1: 8,
+        expected_part2: 2286,
+        ..Default::default()
+    };
+    let solution = Solution {
+        input_data: get_input_for_day(&config.data_dir, YEAR, day),
+        get_solution_part1: get_solution_day2_part1,
+        get_solution_part2: get_solution_day2_part2,
+        params: &PARAMS,
+    };
+    solution.print_solutions(YEAR, day, example, config);
+}
+
+pub fn get_solution_day2_part1(input_data_raw: &str, params: &Params) -> u64 {
+    let games: Vec<Game> = parse_games(input_data_raw).unwrap();
+    let bag: Bag = params.get("bag");
+
+    let valid_game_ids: Vec<u32> = feasible_games(&games, &bag)
+        .iter()
+        .map(|game| game.game_id)
+        .collect_vec();
+    let solution: u32 = valid_game_ids.iter().sum();
+    return solution as u64;
+}
+
+pub fn get_solution_day2_part2(input_data_raw: &str, _params: &Params) -> u64 {
+    let games: Vec<Game> = parse_games(input_data_raw).unwrap();
+    let powers: Vec<u64> = games
+        .iter()
+        .map(|game| game.minimal_bag().power().unwrap())
+        .collect();
+    let solution: u64 = powers.iter().sum();
+    return solution;
+}
+
+/*
+Parses string input contain the game data to generate a vector of 'Game' structs.
+*/
+pub fn parse_games(input_data_raw: &str) -> Result<Vec<Game>, ParseError> {
+    input_data_raw
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(str::parse)
+        .collect()
+}
+
+/// Games that could have been played with the given bag.
+pub fn feasible_games<'a>(games: &'a [Game], bag: &Bag) -> Vec<&'a Game> {
+    games.iter().filter(|game| game.is_feasible(bag)).collect()
+}
+
+/// Smallest bag that every game could have been played with.
+pub fn smallest_bag(games: &[Game]) -> Bag {
+    games
+        .iter()
+        .fold(Bag::default(), |bag, game| bag.union(&game.minimal_bag()))
+}
+
+/*
+Number of cubes of each colour in a bag (or shown in a set), for any colours.
+Colours that aren't mentioned count as zero, so a bag without any blue cubes can't
+cover a set with blue cubes in it. Counts are u64 so a set showing the same colour more
+than once can't overflow when its u32 counts are added up.
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Bag {
+    counts: BTreeMap<String, u64>,
+}
+
+/// Set of a game that needs more cubes than the bag holds, and how many more of each colour.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// Index of the set within the game, counting from 0.
+    pub set_index: usize,
+    pub excess: Bag,
+}
+
+impl Bag {
+    pub fn get(&self, colour: &str) -> u64 {
+        self.counts.get(colour).copied().unwrap_or(0)
+    }
+
+    pub fn set(&mut self, colour: &str, count: u64) {
+        if count == 0 {
+            self.counts.remove(colour);
+        } else {
+            self.counts.insert(colour.to_owned(), count);
+        }
+    }
+
+    /// Colours with at least one cube, in alphabetical order.
+    pub fn colours(&self) -> impl Iterator<Item = (&str, u64)> {
+        self.counts
+            .iter()
+            .map(|(colour, &count)| (colour.as_str(), count))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Whether the bag has at least as many cubes of every colour as `other`.
+    pub fn covers(&self, other: &Bag) -> bool {
+        other
+            .colours()
+            .all(|(colour, count)| self.get(colour) >= count)
+    }
+
+    /// Smallest bag covering both bags, i.e. the most cubes of each colour in either.
+    pub fn union(&self, other: &Bag) -> Bag {
+        let mut union = self.clone();
+        for (colour, count) in other.colours() {
+            union.set(colour, count.max(self.get(colour)));
+        }
+        union
+    }
+
+    /// Cubes of each colour in this bag over what `limit` holds, empty if it covers this bag.
+    pub fn excess_over(&self, limit: &Bag) -> Bag {
+        let mut excess = Bag::default();
+        for (colour, count) in self.colours() {
+            excess.set(colour, count.saturating_sub(limit.get(colour)));
+        }
+        excess
+    }
+
+    /// Product of the number of cubes of each colour in the bag, 1 for an empty bag, or `None`
+    /// if it's too big for a u64.
+    pub fn power(&self) -> Option<u64> {
+        self.colours()
+            .try_fold(1u64, |power, (_, count)| power.checked_mul(count))
+    }
+}
+
+impl<'a> FromIterator<(&'a str, u32)> for Bag {
+    // Counts of the same colour add up
+    fn from_iter<I: IntoIterator<Item = (&'a str, u32)>>(iter: I) -> Self {
+        let mut bag = Bag::default();
+        for (colour, count) in iter {
+            bag.set(colour, bag.get(colour) + count as u64);
+        }
+        bag
+    }
+}
+
+impl From<&GameSet> for Bag {
+    fn from(set: &GameSet) -> Self {
+        set.ball_counts
+            .iter()
+            .map(|count| (count.colour.as_str(), count.count))
+            .collect()
+    }
+}
+
+impl Game {
+    /// Smallest bag the game could have been played with.
+    pub fn minimal_bag(&self) -> Bag {
+        self.game_sets
+            .iter()
+            .fold(Bag::default(), |bag, set| bag.union(&Bag::from(set)))
+    }
+
+    pub fn is_feasible(&self, bag: &Bag) -> bool {
+        bag.covers(&self.minimal_bag())
+    }
+
+    /// Sets that need more cubes than the bag holds, in the order they were shown.
+    pub fn violations(&self, bag: &Bag) -> Vec<Violation> {
+        self.game_sets
+            .iter()
+            .enumerate()
+            .map(|(set_index, set)| Violation {
+                set_index,
+                excess: Bag::from(set).excess_over(bag),
+            })
+            .filter(|violation| !violation.excess.is_empty())
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BallCount {
+    pub colour: String,
+    pub count: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameSet {
+    pub ball_counts: Vec<BallCount>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub game_id: u32,
+    pub game_sets: Vec<GameSet>,
+}
+
+// e.g. "3 blue"
+impl FromStr for BallCount {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (count, colour) = s
+            .split_whitespace()
+            .collect_tuple()
+            .ok_or_else(|| ParseError::new(format!("Expected 'count colour' but found '{s}'")))?;
+
+        if !colour.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(ParseError::new(format!("Invalid colour '{colour}'")));
+        }
+        let count = count
+            .parse()
+            .map_err(|_| ParseError::new(format!("Invalid ball count '{count}' in '{s}'")))?;
+
+        Ok(BallCount {
+            colour: colour.to_owned(),
+            count,
+        })
+    }
+}
+
+// e.g. "3 blue, 4 red"
+impl FromStr for GameSet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let ball_counts = s.split(',').map(str::parse).collect::<Result<_, _>>()?;
+        Ok(GameSet { ball_counts })
+    }
+}
+
+// e.g. "Game 1: 3 blue, 4 red; 1 red, 2 green"
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (game, sets) = key_values(s)?;
+
+        let game_id = game
+            .strip_prefix("Game")
+            .and_then(|id| id.trim().parse().ok())
+            .ok_or_else(|| ParseError::new(format!("Expected 'Game <id>' but found '{game}'")))?;
+        let game_sets = sets.split(';').map(str::parse).collect::<Result<_, _>>()?;
+
+        Ok(Game { game_id, game_sets })
+    }
+}
+
+impl fmt::Display for BallCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.count, self.colour)
+    }
+}
+
+impl fmt::Display for GameSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.ball_counts.iter().join(", "))
+    }
+}
+
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Game {}: {}",
+            self.game_id,
+            self.game_sets.iter().join("; ")
+        )
+    }
+}
+
+// e.g. "12 red, 13 green, 14 blue", in the same form as a set but with u64 counts
+impl FromStr for Bag {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut bag = Bag::default();
+        // An empty bag is written as an empty string
+        if s.trim().is_empty() {
+            return Ok(bag);
+        }
+        for entry in s.split(',') {
+            let (count, colour) = entry.split_whitespace().collect_tuple().ok_or_else(|| {
+                ParseError::new(format!("Expected 'count colour' but found '{entry}'"))
+            })?;
+            if !colour.chars().all(|c| c.is_ascii_alphabetic()) {
+                return Err(ParseError::new(format!("Invalid colour '{colour}'")));
+            }
+            let total = count
+                .parse()
+                .ok()
+                .and_then(|count: u64| count.checked_add(bag.get(colour)))
+                .ok_or_else(|| ParseError::new(format!("Invalid ball count '{count}' in '{s}'")))?;
+            bag.set(colour, total);
+        }
+        Ok(bag)
+    }
+}
+
+impl fmt::Display for Bag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let counts = self
+            .colours()
+            .map(|(colour, count)| format!("{count} {colour}"));
+        write!(f, "{}", counts.format(", "))
+    }
+}
@@ -0,0 +1,305 @@
+use std::str::FromStr;
+use textwrap::dedent;
+
+use super::YEAR;
+use crate::config::Config;
+use crate::helpers::{parses_as, print_drawing, Example, Param, ParamValue, Params, Solution};
+use crate::utils::parse::ParseError;
+use crate::utils::{get_input_for_day, Grid};
+use render::{render, RenderFormat};
+
+pub mod render;
+
+pub const PARAMS: [Param; 4] = [
+    Param {
+        name: "gear_symbol",
+        default: ParamValue::Text("*"),
+        description: "Symbol that gears are marked with in part 2, or 'any' for every symbol",
+        parse: |value| parse_gear_symbol(value).map(|_| ()),
+    },
+    Param {
+        name: "gear_neighbours",
+        default: ParamValue::Integer(2),
+        description: "Number of part numbers a gear must be next to in part 2",
+        parse: parses_as::<usize>,
+    },
+    Param {
+        name: "gear_aggregate",
+        default: ParamValue::Text("product"),
+        description: "How a gear's numbers combine into its ratio: product, sum, min or max",
+        parse: parses_as::<Aggregate>,
+    },
+    Param {
+        name: "render",
+        default: ParamValue::Text("ansi"),
+        description: "How --explain draws the schematic: ansi, html or svg",
+        parse: parses_as::<RenderFormat>,
+    },
+];
+
+pub fn print_solutions_day3(config: &Config) {
+    let day: u32 = 3;
+    let example = Example {
+        input_data: dedent(
+            "
+            467..114..
+            ...*......
+            ..35..633.
+            ......#...
+            617*......
+            .....+.58.
+            ..592.....
+            ......755.
+            ...$.*....
+            .664.598..
+        ",
+        ),
+        expected_part1: 4361,
+        expected_part2: 467835,
+        ..Default::default()
+    };
+    let input_data = get_input_for_day(&config.data_dir, YEAR, day);
+    let solution = Solution {
+        input_data: input_data.clone(),
+        get_solution_part1: get_solution_day3_part1,
+        get_solution_part2: get_solution_day3_part2,
+        params: &PARAMS,
+    };
+    solution.print_solutions(YEAR, day, example, config);
+
+    if config.explain {
+        // Part numbers, numbers that aren't parts and gears each get their own colour
+        let params = config.resolved_params(YEAR, day, &PARAMS);
+        let schematic: Schematic = input_data.parse().unwrap();
+        let format: RenderFormat = params.get("render");
+        let drawing = render(&schematic, &gear_rule(&params), format);
+        print_drawing(config.output, YEAR, day, &drawing);
+    }
+}
+
+pub fn get_solution_day3_part1(input_data_raw: &str, _params: &Params) -> u64 {
+    let schematic: Schematic = input_data_raw.parse().unwrap();
+    return schematic.part_numbers().map(|number| number.value).sum();
+}
+
+pub fn get_solution_day3_part2(input_data_raw: &str, params: &Params) -> u64 {
+    let schematic: Schematic = input_data_raw.parse().unwrap();
+    return schematic.gear_ratios(&gear_rule(params)).iter().sum();
+}
+
+pub fn gear_rule(params: &Params) -> GearRule {
+    let symbol: String = params.get("gear_symbol");
+    GearRule {
+        symbol: parse_gear_symbol(&symbol).unwrap(),
+        neighbours: params.get("gear_neighbours"),
+        aggregate: params.get("gear_aggregate"),
+    }
+}
+
+/// Reads the gear symbol parameter, which is either a single character or "any".
+fn parse_gear_symbol(value: &str) -> Result<Option<char>, String> {
+    if value == "any" {
+        return Ok(None);
+    }
+    value
+        .parse()
+        .map(Some)
+        .map_err(|_| "expected a single character or 'any'".to_owned())
+}
+
+pub fn get_numbers(schematic: &Grid<char>) -> Vec<Number> {
+    let mut numbers: Vec<Number> = vec![];
+
+    for (row_idx, row) in schematic.rows().enumerate() {
+        let mut col_start_idx: usize = 0;
+        let mut scanning_number = false;
+
+        for (col_idx, char) in row.iter().enumerate() {
+            if char.is_ascii_digit() && !scanning_number {
+                col_start_idx = col_idx;
+                scanning_number = true;
+            }
+
+            let is_number_end = !row.get(col_idx + 1).is_some_and(char::is_ascii_digit);
+            if scanning_number && is_number_end {
+                // Extract and parse value
+                let value: u64 = row[col_start_idx..col_idx + 1]
+                    .iter()
+                    .collect::<String>()
+                    .parse()
+                    .unwrap();
+
+                let number = Number {
+                    row_idx,
+                    col_start_idx,
+                    col_end_idx: col_idx,
+                    value,
+                    symbols: vec![],
+                };
+
+                numbers.push(number);
+                scanning_number = false;
+            }
+        }
+    }
+    return numbers;
+}
+
+/*
+Engine schematic, with its numbers and symbols linked up into a graph: each symbol lists
+the numbers next to it (including diagonally) and each number the symbols next to it.
+*/
+#[derive(Debug, Clone)]
+pub struct Schematic {
+    pub grid: Grid<char>,
+    pub numbers: Vec<Number>,
+    pub symbols: Vec<Symbol>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Symbol {
+    pub value: char,
+    pub row_idx: usize,
+    pub col_idx: usize,
+    /// Indices into the schematic's numbers, in the order they appear.
+    pub numbers: Vec<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Number {
+    pub row_idx: usize,
+    pub col_start_idx: usize,
+    pub col_end_idx: usize,
+    pub value: u64,
+    /// Indices into the schematic's symbols, in the order they appear.
+    pub symbols: Vec<usize>,
+}
+
+/// Which symbols count as gears, and how the numbers next to them give the gear's ratio.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GearRule {
+    /// Symbol gears are marked with, or `None` if any symbol can be a gear.
+    pub symbol: Option<char>,
+    /// Exact number of part numbers a gear is next to.
+    pub neighbours: usize,
+    pub aggregate: Aggregate,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregate {
+    Product,
+    Sum,
+    Min,
+    Max,
+}
+
+impl Schematic {
+    /*
+    Links each symbol to the numbers next to it in a single pass over the grid, by first
+    recording which number (if any) covers each cell.
+    */
+    pub fn new(grid: Grid<char>) -> Schematic {
+        let mut numbers = get_numbers(&grid);
+        let mut number_at: Grid<Option<usize>> = Grid::new(grid.n_rows(), grid.n_cols(), None);
+        for (idx, number) in numbers.iter().enumerate() {
+            for col_idx in number.col_start_idx..=number.col_end_idx {
+                number_at[(number.row_idx, col_idx)] = Some(idx);
+            }
+        }
+
+        let mut symbols: Vec<Symbol> = vec![];
+        for ((row_idx, col_idx), &value) in grid.iter() {
+            if value.is_ascii_digit() || value == '.' {
+                continue;
+            }
+            // A number spanning several neighbouring cells is only linked once
+            let mut neighbours: Vec<usize> = grid
+                .neighbours8(row_idx, col_idx)
+                .filter_map(|cell| number_at[cell])
+                .collect();
+            neighbours.sort_unstable();
+            neighbours.dedup();
+
+            for &number in neighbours.iter() {
+                numbers[number].symbols.push(symbols.len());
+            }
+            symbols.push(Symbol {
+                value,
+                row_idx,
+                col_idx,
+                numbers: neighbours,
+            });
+        }
+
+        Schematic {
+            grid,
+            numbers,
+            symbols,
+        }
+    }
+
+    /// Numbers next to at least one symbol.
+    pub fn part_numbers(&self) -> impl Iterator<Item = &Number> {
+        self.numbers.iter().filter(|number| number.is_part_number())
+    }
+
+    /// Symbols that are gears under the given rule.
+    pub fn gears<'a>(&'a self, rule: &'a GearRule) -> impl Iterator<Item = &'a Symbol> {
+        self.symbols.iter().filter(|symbol| {
+            rule.symbol.is_none_or(|gear| symbol.value == gear)
+                && symbol.numbers.len() == rule.neighbours
+        })
+    }
+
+    /// Ratio of each gear under the given rule, combining the numbers next to it.
+    pub fn gear_ratios(&self, rule: &GearRule) -> Vec<u64> {
+        self.gears(rule)
+            .map(|gear| {
+                let values = gear.numbers.iter().map(|&idx| self.numbers[idx].value);
+                rule.aggregate.apply(values)
+            })
+            .collect()
+    }
+}
+
+impl Number {
+    pub fn is_part_number(&self) -> bool {
+        !self.symbols.is_empty()
+    }
+}
+
+impl Aggregate {
+    /// Combines the values. With no values, a product is 1 and the others are 0.
+    pub fn apply<I: IntoIterator<Item = u64>>(&self, values: I) -> u64 {
+        let values = values.into_iter();
+        match self {
+            Aggregate::Product => values.product(),
+            Aggregate::Sum => values.sum(),
+            Aggregate::Min => values.min().unwrap_or(0),
+            Aggregate::Max => values.max().unwrap_or(0),
+        }
+    }
+}
+
+impl FromStr for Schematic {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid: Grid<char> = s.parse().map_err(ParseError::new)?;
+        Ok(Schematic::new(grid))
+    }
+}
+
+impl FromStr for Aggregate {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "product" => Ok(Aggregate::Product),
+            "sum" => Ok(Aggregate::Sum),
+            "min" => Ok(Aggregate::Min),
+            "max" => Ok(Aggregate::Max),
+            _ => Err(ParseError::new(format!("Unknown aggregate '{s}'"))),
+        }
+    }
+}
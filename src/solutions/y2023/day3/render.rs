@@ -0,0 +1,186 @@
+use std::fmt::Write;
+use std::str::FromStr;
+
+use super::{GearRule, Schematic};
+use crate::utils::parse::ParseError;
+use crate::utils::Grid;
+
+/// What each cell of a schematic turns out to be, for drawing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CellKind {
+    Empty,
+    PartNumber,
+    /// Digit of a number that isn't next to any symbol, so isn't counted in part 1.
+    LooseNumber,
+    Gear,
+    Symbol,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderFormat {
+    Ansi,
+    Html,
+    Svg,
+}
+
+// Width and height of each cell in an SVG drawing
+const SVG_CELL: (usize, usize) = (10, 16);
+
+impl CellKind {
+    fn ansi_code(&self) -> &'static str {
+        match self {
+            CellKind::Empty => "\x1b[2m",
+            CellKind::PartNumber => "\x1b[32m",
+            CellKind::LooseNumber => "\x1b[31m",
+            CellKind::Gear => "\x1b[1;33m",
+            CellKind::Symbol => "\x1b[36m",
+        }
+    }
+
+    fn colour(&self) -> &'static str {
+        match self {
+            CellKind::Empty => "#777777",
+            CellKind::PartNumber => "#2e9e44",
+            CellKind::LooseNumber => "#d03030",
+            CellKind::Gear => "#e0a800",
+            CellKind::Symbol => "#2090c0",
+        }
+    }
+
+    fn class(&self) -> &'static str {
+        match self {
+            CellKind::Empty => "empty",
+            CellKind::PartNumber => "part",
+            CellKind::LooseNumber => "loose",
+            CellKind::Gear => "gear",
+            CellKind::Symbol => "symbol",
+        }
+    }
+}
+
+/// Sorts every cell of the schematic into a `CellKind`, with gears picked out by the rule.
+pub fn annotate(schematic: &Schematic, rule: &GearRule) -> Grid<CellKind> {
+    let grid = &schematic.grid;
+    let mut kinds = Grid::new(grid.n_rows(), grid.n_cols(), CellKind::Empty);
+
+    for number in schematic.numbers.iter() {
+        let kind = if number.is_part_number() {
+            CellKind::PartNumber
+        } else {
+            CellKind::LooseNumber
+        };
+        for col_idx in number.col_start_idx..=number.col_end_idx {
+            kinds[(number.row_idx, col_idx)] = kind;
+        }
+    }
+    for symbol in schematic.symbols.iter() {
+        kinds[(symbol.row_idx, symbol.col_idx)] = CellKind::Symbol;
+    }
+    for gear in schematic.gears(rule) {
+        kinds[(gear.row_idx, gear.col_idx)] = CellKind::Gear;
+    }
+    kinds
+}
+
+pub fn render(schematic: &Schematic, rule: &GearRule, format: RenderFormat) -> String {
+    let kinds = annotate(schematic, rule);
+    match format {
+        RenderFormat::Ansi => render_ansi(&schematic.grid, &kinds),
+        RenderFormat::Html => render_html(&schematic.grid, &kinds),
+        RenderFormat::Svg => render_svg(&schematic.grid, &kinds),
+    }
+}
+
+// Only switches colour where the kind of cell changes, to keep the output small
+fn render_ansi(grid: &Grid<char>, kinds: &Grid<CellKind>) -> String {
+    let mut output = String::new();
+    for (row, row_kinds) in grid.rows().zip(kinds.rows()) {
+        let mut current: Option<CellKind> = None;
+        for (&c, &kind) in row.iter().zip(row_kinds.iter()) {
+            if current != Some(kind) {
+                output.push_str(kind.ansi_code());
+                current = Some(kind);
+            }
+            output.push(c);
+        }
+        output.push_str("\x1b[0m\n");
+    }
+    output
+}
+
+fn render_html(grid: &Grid<char>, kinds: &Grid<CellKind>) -> String {
+    let mut output = String::from("<pre class=\"schematic\">\n");
+    for (row, row_kinds) in grid.rows().zip(kinds.rows()) {
+        let mut current: Option<CellKind> = None;
+        for (&c, &kind) in row.iter().zip(row_kinds.iter()) {
+            if current != Some(kind) {
+                if current.is_some() {
+                    output.push_str("</span>");
+                }
+                let (class, colour) = (kind.class(), kind.colour());
+                write!(output, "<span class=\"{class}\" style=\"color:{colour}\">").unwrap();
+                current = Some(kind);
+            }
+            output.push_str(&escape(c));
+        }
+        output.push_str("</span>\n");
+    }
+    output.push_str("</pre>\n");
+    output
+}
+
+fn render_svg(grid: &Grid<char>, kinds: &Grid<CellKind>) -> String {
+    let (cell_width, cell_height) = SVG_CELL;
+    let (width, height) = (grid.n_cols() * cell_width, grid.n_rows() * cell_height);
+
+    let mut output = String::new();
+    writeln!(
+        output,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+         font-family=\"monospace\" font-size=\"{cell_height}\">"
+    )
+    .unwrap();
+    output.push_str("<rect width=\"100%\" height=\"100%\" fill=\"#101018\"/>\n");
+    for ((row_idx, col_idx), &c) in grid.iter() {
+        let kind = kinds[(row_idx, col_idx)];
+        if kind == CellKind::Empty {
+            continue;
+        }
+        let (x, y) = (col_idx * cell_width, (row_idx + 1) * cell_height - 3);
+        writeln!(
+            output,
+            "<text x=\"{x}\" y=\"{y}\" fill=\"{}\" class=\"{}\">{}</text>",
+            kind.colour(),
+            kind.class(),
+            escape(c)
+        )
+        .unwrap();
+    }
+    output.push_str("</svg>\n");
+    output
+}
+
+fn escape(c: char) -> String {
+    match c {
+        '&' => "&amp;".to_owned(),
+        '<' => "&lt;".to_owned(),
+        '>' => "&gt;".to_owned(),
+        '"' => "&quot;".to_owned(),
+        c => c.to_string(),
+    }
+}
+
+impl FromStr for RenderFormat {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ansi" => Ok(RenderFormat::Ansi),
+            "html" => Ok(RenderFormat::Html),
+            "svg" => Ok(RenderFormat::Svg),
+            _ => Err(ParseError::new(format!(
+                "Unknown render format '{s}', expected 'ansi', 'html' or 'svg'"
+            ))),
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use hashbrown::HashSet;
+use itertools::Itertools;
+use num::{One, Zero};
+use serde::Serialize;
+use std::fmt;
+use std::ops::AddAssign;
+use std::str::FromStr;
+use textwrap::dedent;
+
+use super::YEAR;
+use crate::config::Config;
+use crate::helpers::{print_explanation, Example, Explain, Params, Solution};
+use crate::utils::get_input_for_day;
+use crate::utils::parse::{ints, key_values, ParseError};
+
+pub fn print_solutions_day4(config: &Config) {
+    let day: u32 = 4;
+    let example = Example {
+        input_data: dedent(
+            "
+            Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+            Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+            Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+            Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+            Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+            Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+        ",
+        ),
+        expected_part1: 13,
+        expected_part2: 30,
+        ..Default::default()
+    };
+    let input_data = get_input_for_day(&config.data_dir, YEAR, day);
+    let solution = Solution {
+        input_data: input_data.clone(),
+        get_solution_part1: get_solution_day4_part1,
+        get_solution_part2: get_solution_day4_part2,
+        params: &[],
+    };
+    solution.print_solutions(YEAR, day, example, config);
+
+    if config.explain {
+        let cards = parse_cards(&input_data).unwrap();
+        let trace: Vec<CardTrace<u64>> = cascade(&cards).unwrap();
+        print_explanation(config.output, YEAR, day, 2, &trace);
+    }
+}
+
+pub fn get_solution_day4_part1(input_data_raw: &str, _params: &Params) -> u64 {
+    let cards = parse_cards(input_data_raw).unwrap();
+    let mut solution: u64 = 0;
+
+    for card in cards.iter() {
+        let matches = card.matches();
+        if matches > 0 {
+            solution += 1 << (matches - 1);
+        }
+    }
+    solution
+}
+
+pub fn get_solution_day4_part2(input_data_raw: &str, _params: &Params) -> u64 {
+    let cards = parse_cards(input_data_raw).unwrap();
+    let trace: Vec<CardTrace<u64>> = cascade(&cards).unwrap();
+    trace.iter().map(|card| card.copies).sum()
+}
+
+pub fn parse_cards(input_data_raw: &str) -> Result<Vec<Card>, ParseError> {
+    input_data_raw
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(str::parse)
+        .collect()
+}
+
+/// Checks the cards are numbered 1, 2, 3, ... in order, since copies are won by card number.
+pub fn validate_ids(cards: &[Card]) -> Result<(), String> {
+    for (idx, card) in cards.iter().enumerate() {
+        let expected = idx as u32 + 1;
+        if card.card_id == expected {
+            continue;
+        }
+        let previous = match idx {
+            0 => "at the start".to_owned(),
+            _ => format!("after card {}", cards[idx - 1].card_id),
+        };
+        return Err(if card.card_id > expected {
+            format!(
+                "Card {expected} is missing, found card {} {previous}",
+                card.card_id
+            )
+        } else {
+            format!(
+                "Card {} is out of order, expected card {expected} {previous}",
+                card.card_id
+            )
+        });
+    }
+    Ok(())
+}
+
+/*
+Works through the cards in order, where each copy of a card with n matches wins a copy of
+each of the next n cards. Copies are counted in any integer type, e.g. `u64`, or `BigUint`
+for inputs where they'd overflow. Copies won past the last card are dropped.
+Fails if the cards aren't numbered in order, see `validate_ids`.
+*/
+pub fn cascade<N>(cards: &[Card]) -> Result<Vec<CardTrace<N>>, String>
+where
+    N: Clone + Zero + One + for<'a> AddAssign<&'a N>,
+{
+    validate_ids(cards)?;
+    let mut copies: Vec<N> = vec![N::one(); cards.len()];
+    let mut trace: Vec<CardTrace<N>> = vec![];
+
+    for (idx, card) in cards.iter().enumerate() {
+        let matches = card.matches();
+        let n_won = matches.min(cards.len() - idx - 1);
+
+        // Each copy of this card wins one more copy of each of the next cards
+        let (this_card, later_cards) = copies[idx..].split_first_mut().unwrap();
+        let mut won = N::zero();
+        for later_card in later_cards[..n_won].iter_mut() {
+            *later_card += &*this_card;
+            won += &*this_card;
+        }
+        trace.push(CardTrace {
+            card_id: card.card_id,
+            matches,
+            copies: this_card.clone(),
+            won,
+        });
+    }
+    Ok(trace)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Card {
+    pub card_id: u32,
+    pub winning_numbers: Vec<u32>,
+    pub my_numbers: Vec<u32>,
+}
+
+/// How many copies of a card there ended up being, and how many copies of later cards they won.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CardTrace<N> {
+    pub card_id: u32,
+    pub matches: usize,
+    pub copies: N,
+    pub won: N,
+}
+
+impl Card {
+    /// Numbers I have that are also winning numbers.
+    pub fn matching_numbers(&self) -> HashSet<u32> {
+        let winning_numbers: HashSet<&u32> = self.winning_numbers.iter().collect();
+        self.my_numbers
+            .iter()
+            .filter(|x| winning_numbers.contains(x))
+            .copied()
+            .collect()
+    }
+
+    pub fn matches(&self) -> usize {
+        self.matching_numbers().len()
+    }
+}
+
+// e.g. "Card 1: 41 48 83 | 83 86  6"
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (card, numbers) = key_values(s)?;
+
+        let card_id = card
+            .strip_prefix("Card")
+            .and_then(|id| id.trim().parse().ok())
+            .ok_or_else(|| ParseError::new(format!("Expected 'Card <id>' but found '{card}'")))?;
+        let (winning_numbers, my_numbers) = numbers
+            .split_once('|')
+            .ok_or_else(|| ParseError::new(format!("Expected '|' between numbers in '{s}'")))?;
+
+        Ok(Card {
+            card_id,
+            winning_numbers: ints(winning_numbers)?,
+            my_numbers: ints(my_numbers)?,
+        })
+    }
+}
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Numbers are right aligned in columns of 2, as in the puzzle input
+        let winning_numbers = self.winning_numbers.iter().map(|x| format!("{x:>2}"));
+        let my_numbers = self.my_numbers.iter().map(|x| format!("{x:>2}"));
+        write!(
+            f,
+            "Card {}: {} | {}",
+            self.card_id,
+            winning_numbers.format(" "),
+            my_numbers.format(" ")
+        )
+    }
+}
+
+impl<N: Serialize + fmt::Display> Explain for CardTrace<N> {
+    const COLUMNS: &'static [&'static str] = &["card", "matches", "copies", "won"];
+
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.card_id.to_string(),
+            self.matches.to_string(),
+            self.copies.to_string(),
+            self.won.to_string(),
+        ]
+    }
+}
@@ -0,0 +1,478 @@
+use hashbrown::HashMap;
+use itertools::Itertools;
+use std::fmt;
+use std::str::FromStr;
+use textwrap::dedent;
+
+use super::YEAR;
+use crate::config::Config;
+use crate::helpers::{Example, Params, Solution};
+use crate::utils::graph::{bfs_path, topological_sort};
+use crate::utils::parse::{blocks, ints, values_for, ParseError};
+use crate::utils::{get_input_for_day, Interval, IntervalSet, PiecewiseOffsetMap};
+
+pub fn print_solutions_day5(config: &Config) {
+    let day: u32 = 5;
+    let example = Example {
+        input_data: dedent(
+            "
+            seeds: 79 14 55 13
+
+            seed-to-soil map:
+            50 98 2
+            52 50 48
+
+            soil-to-fertilizer map:
+            0 15 37
+            37 52 2
+            39 0 15
+
+            fertilizer-to-water map:
+            49 53 8
+            0 11 42
+            42 0 7
+            57 7 4
+
+            water-to-light map:
+            88 18 7
+            18 25 70
+
+            light-to-temperature map:
+            45 77 23
+            81 45 19
+            68 64 13
+
+            temperature-to-humidity map:
+            0 69 1
+            1 0 69
+
+            humidity-to-location map:
+            60 56 37
+            56 93 4
+                    ",
+        ),
+        expected_part1: 35,
+        expected_part2: 46,
+        ..Default::default()
+    };
+    let solution = Solution {
+        input_data: get_input_for_day(&config.data_dir, YEAR, day),
+        get_solution_part1: get_solution_day5_part1,
+        get_solution_part2: get_solution_day5_part2,
+        params: &[],
+    };
+    solution.print_solutions(YEAR, day, example, config);
+}
+
+pub fn get_solution_day5_part1(input_data_raw: &str, _params: &Params) -> u64 {
+    let almanac: Almanac = input_data_raw.parse().unwrap();
+    let seed_values: Vec<(u64, u64)> = almanac.seeds.iter().map(|x| (*x, 1)).collect_vec();
+
+    get_min_location(&seed_values, &almanac).unwrap()
+}
+
+pub fn get_solution_day5_part2(input_data_raw: &str, _params: &Params) -> u64 {
+    let almanac: Almanac = input_data_raw.parse().unwrap();
+    let seed_values: Vec<(u64, u64)> = almanac.seed_ranges().unwrap();
+
+    get_min_location(&seed_values, &almanac).unwrap()
+}
+
+/*
+Lowest location for any of the seeds, given as (range_start, length) pairs.
+Fails if there are no seeds, or if a range runs past u64::MAX.
+*/
+pub fn get_min_location(seed_values: &[(u64, u64)], almanac: &Almanac) -> Result<u64, String> {
+    /*
+    Rather than mapping individual values within a range, which is computationally intensive
+    we can just map whole ranges of values at once, splitting them up where they cross the
+    boundaries defining the maps.
+    */
+    let seeds: IntervalSet<i128> = seed_values
+        .iter()
+        .map(|&(x, len)| value_range(x, len))
+        .collect::<Result<_, _>>()?;
+    let locations = almanac.map_ranges(&seeds, "seed", "location")?;
+
+    let min_location = locations
+        .min()
+        .ok_or_else(|| "No seeds to find a location for".to_owned())?;
+    return to_value(min_location);
+}
+
+/*
+Range of `length` values from `start`. Ranges of u64 values are held as i128s, so they can
+run right up to u64::MAX (ending at 2^64) and maps can move values down as well as up.
+*/
+pub fn value_range(start: u64, length: u64) -> Result<Interval<i128>, String> {
+    let end = start as i128 + length as i128;
+    if end > 1 << 64 {
+        return Err(format!(
+            "Range of {length} values from {start} runs past u64::MAX"
+        ));
+    }
+    Ok(Interval::new(start as i128, end))
+}
+
+fn to_value(x: i128) -> Result<u64, String> {
+    u64::try_from(x).map_err(|_| format!("Value {x} is outside the range of a u64"))
+}
+
+/// Moves `length` values from `src_start` to `dest_start`, e.g. "50 98 2" in the almanac.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Map {
+    pub dest_start: u64,
+    pub src_start: u64,
+    pub length: u64,
+}
+
+/// Maps from one category to the next, e.g. "seed-to-soil".
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CategoryMap {
+    pub source: String,
+    pub destination: String,
+    pub maps: Vec<Map>,
+}
+
+/*
+Several category maps composed into one function, e.g. straight from seed to location.
+Each map only moves values by an offset, so the composition does too, with the offset
+changing at a list of breakpoints: values from `breakpoints[i]` up to the next breakpoint
+move by `offsets[i]`, and the last offset applies to everything past the last breakpoint.
+*/
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ComposedMap {
+    breakpoints: Vec<i128>,
+    offsets: Vec<i128>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    pub category_maps: Vec<CategoryMap>,
+}
+
+impl Map {
+    pub fn src_end(&self) -> i128 {
+        self.src_start as i128 + self.length as i128
+    }
+
+    pub fn dest_end(&self) -> i128 {
+        self.dest_start as i128 + self.length as i128
+    }
+
+    /// The source range of the map along with how far it moves values.
+    pub fn offset_piece(&self) -> (Interval<i128>, i128) {
+        (
+            Interval::new(self.src_start as i128, self.src_end()),
+            self.dest_start as i128 - self.src_start as i128,
+        )
+    }
+}
+
+impl CategoryMap {
+    pub fn name(&self) -> String {
+        format!("{}-to-{}", self.source, self.destination)
+    }
+
+    /// The maps as a single function, failing if any of their source ranges overlap.
+    pub fn offset_map(&self) -> Result<PiecewiseOffsetMap<i128>, String> {
+        PiecewiseOffsetMap::new(self.maps.iter().map(Map::offset_piece))
+            .map_err(|e| format!("Invalid {} map: {e}", self.name()))
+    }
+}
+
+impl Almanac {
+    /// Seeds read as (range_start, length) pairs, as in part 2.
+    pub fn seed_ranges(&self) -> Result<Vec<(u64, u64)>, String> {
+        if !self.seeds.len().is_multiple_of(2) {
+            return Err(format!(
+                "Seeds should come in (start, length) pairs, got {} values",
+                self.seeds.len()
+            ));
+        }
+        Ok(self.seeds.iter().copied().tuples().collect())
+    }
+
+    /// Categories each category has a map to, e.g. "seed" -> ["soil"].
+    pub fn category_graph(&self) -> HashMap<String, Vec<String>> {
+        let mut graph: HashMap<String, Vec<String>> = HashMap::new();
+        for category_map in self.category_maps.iter() {
+            graph
+                .entry(category_map.source.clone())
+                .or_default()
+                .push(category_map.destination.clone());
+        }
+        graph
+    }
+
+    /*
+    Maps to apply in turn to get from one category to another, following the fewest maps
+    if there's a choice. Empty when going from a category to itself.
+    Fails if there's no way to get there, or if the maps from `source` loop back on themselves.
+    */
+    pub fn chain(&self, source: &str, destination: &str) -> Result<Vec<&CategoryMap>, String> {
+        let graph = self.category_graph();
+        if topological_sort(&graph, [source.to_owned()]).is_none() {
+            return Err(format!("Maps from '{source}' form a cycle"));
+        }
+        let path = bfs_path(&graph, source.to_owned(), |category| {
+            category == destination
+        })
+        .ok_or_else(|| format!("No chain of maps from '{source}' to '{destination}'"))?;
+
+        let chain = path
+            .iter()
+            .tuple_windows()
+            .map(|(from, to)| {
+                self.category_maps
+                    .iter()
+                    .find(|map| &map.source == from && &map.destination == to)
+                    .expect("Each step of the path should have a map")
+            })
+            .collect();
+        Ok(chain)
+    }
+
+    /// Maps a single value from one category to another, e.g. a seed to its location.
+    pub fn map_value(&self, value: u64, source: &str, destination: &str) -> Result<u64, String> {
+        let mut value = value as i128;
+        for category_map in self.chain(source, destination)? {
+            value = category_map.offset_map()?.map_value(value);
+        }
+        to_value(value)
+    }
+
+    /// Composes the chain of maps from one category to another into a single function.
+    pub fn compose(&self, source: &str, destination: &str) -> Result<ComposedMap, String> {
+        let chain = self.chain(source, destination)?;
+
+        // Past the end of every range nothing moves, and nothing is moved there
+        let limit = chain
+            .iter()
+            .flat_map(|category_map| category_map.maps.iter())
+            .map(|map| map.src_end().max(map.dest_end()))
+            .max()
+            .unwrap_or(0);
+
+        let mut composed = ComposedMap::identity();
+        for category_map in chain {
+            composed = composed.then(&category_map.offset_map()?, limit);
+        }
+        Ok(composed)
+    }
+
+    /// Maps ranges of values from one category to another, see `value_range`.
+    pub fn map_ranges(
+        &self,
+        ranges: &IntervalSet<i128>,
+        source: &str,
+        destination: &str,
+    ) -> Result<IntervalSet<i128>, String> {
+        let mut ranges = ranges.clone();
+        for category_map in self.chain(source, destination)? {
+            ranges = category_map.offset_map()?.map_set(&ranges);
+        }
+        Ok(ranges)
+    }
+}
+
+impl ComposedMap {
+    // Moves nothing, as a single segment from 0
+    fn identity() -> ComposedMap {
+        ComposedMap {
+            breakpoints: vec![0],
+            offsets: vec![0],
+        }
+    }
+
+    /// Values where the offset changes, starting from 0.
+    pub fn breakpoints(&self) -> &[i128] {
+        &self.breakpoints
+    }
+
+    /// Each range of values with the offset it's moved by, where the last range has no end.
+    pub fn segments(&self) -> impl Iterator<Item = (i128, Option<i128>, i128)> + '_ {
+        (0..self.breakpoints.len()).map(|i| {
+            let end = self.breakpoints.get(i + 1).copied();
+            (self.breakpoints[i], end, self.offsets[i])
+        })
+    }
+
+    /// Where a value ends up, or an error if a map moves it outside the range of a u64.
+    pub fn apply(&self, value: u64) -> Result<u64, String> {
+        let value = value as i128;
+        let idx = self.breakpoints.partition_point(|&x| x <= value);
+        to_value(value + self.offsets[idx.saturating_sub(1)])
+    }
+
+    /// Every value that maps to `value`, in increasing order, e.g. the seeds for a location.
+    pub fn inverse(&self, value: u64) -> Vec<u64> {
+        self.segments()
+            .map(|(start, end, offset)| (start, end, value as i128 - offset))
+            .filter(|&(start, end, x)| start <= x && end.is_none_or(|end| x < end))
+            .filter_map(|(_, _, x)| u64::try_from(x).ok())
+            .collect()
+    }
+
+    /*
+    Lowest value that any of the inputs map to, or `None` if there are no inputs. Within a
+    segment values keep their order, so only the first input in each segment needs checking.
+    Fails like `get_min_location` if the lowest value is outside the range of a u64.
+    */
+    pub fn min_over(&self, inputs: &IntervalSet<i128>) -> Result<Option<u64>, String> {
+        let mut min: Option<i128> = None;
+        for (start, end, offset) in self.segments() {
+            let segment = Interval::new(start, end.unwrap_or(i128::MAX));
+            for input in inputs.intervals() {
+                if let Some(overlap) = segment.intersection(input) {
+                    let value = overlap.start + offset;
+                    min = Some(min.map_or(value, |min| min.min(value)));
+                }
+            }
+        }
+        min.map(to_value).transpose()
+    }
+
+    // Follows this function with the given map, splitting segments where they land across pieces
+    fn then(&self, map: &PiecewiseOffsetMap<i128>, limit: i128) -> ComposedMap {
+        let cuts = map
+            .pieces()
+            .iter()
+            .flat_map(|(interval, _)| [interval.start, interval.end])
+            .collect_vec();
+
+        let mut composed = ComposedMap {
+            breakpoints: vec![],
+            offsets: vec![],
+        };
+        for (start, end, offset) in self.segments() {
+            // Beyond the limit nothing moves, which is added back at the end
+            let end = end.map_or(limit, |end| end.min(limit));
+            if start >= end {
+                continue;
+            }
+            let (image_start, image_end) = (start + offset, end + offset);
+            let inner_cuts = cuts
+                .iter()
+                .copied()
+                .filter(|&cut| image_start < cut && cut < image_end);
+            for cut_start in [image_start].into_iter().chain(inner_cuts) {
+                let moved = map.map_value(cut_start) - cut_start;
+                composed.push(cut_start - offset, offset + moved);
+            }
+        }
+        composed.push(limit, 0);
+        composed
+    }
+
+    // Adds a segment, merging it into the last one if they move values by the same amount
+    fn push(&mut self, start: i128, offset: i128) {
+        if self.offsets.last() != Some(&offset) {
+            self.breakpoints.push(start);
+            self.offsets.push(offset);
+        }
+    }
+}
+
+// e.g. "50 98 2", given as (dest_start, src_start, length)
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (dest_start, src_start, length) = ints(s)?
+            .into_iter()
+            .collect_tuple()
+            .ok_or_else(|| ParseError::new(format!("Expected 3 values in map '{s}'")))?;
+
+        let map = Map {
+            dest_start,
+            src_start,
+            length,
+        };
+        if map.src_end().max(map.dest_end()) > 1 << 64 {
+            return Err(ParseError::new(format!("Map '{s}' runs past u64::MAX")));
+        }
+        Ok(map)
+    }
+}
+
+/*
+e.g.
+    seed-to-soil map:
+    50 98 2
+    52 50 48
+The maps are sorted by their source start.
+*/
+impl FromStr for CategoryMap {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.trim().lines();
+        let header = lines.next().unwrap_or_default();
+
+        let (source, destination) = header
+            .strip_suffix(" map:")
+            .and_then(|name| name.split_once("-to-"))
+            .ok_or_else(|| {
+                ParseError::new(format!("Expected 'X-to-Y map:' but found '{header}'"))
+            })?;
+        let maps = lines
+            .map(str::parse)
+            .collect::<Result<Vec<Map>, _>>()?
+            .into_iter()
+            .sorted_by_key(|map| map.src_start)
+            .collect_vec();
+
+        Ok(CategoryMap {
+            source: source.to_owned(),
+            destination: destination.to_owned(),
+            maps,
+        })
+    }
+}
+
+impl FromStr for Almanac {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let inputs = blocks(s);
+        let seeds_input = inputs
+            .first()
+            .ok_or_else(|| ParseError::new("Almanac is empty"))?;
+
+        Ok(Almanac {
+            seeds: ints(values_for(seeds_input, "seeds")?)?,
+            category_maps: inputs[1..]
+                .iter()
+                .map(|x| x.parse())
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.dest_start, self.src_start, self.length)
+    }
+}
+
+impl fmt::Display for CategoryMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} map:", self.name())?;
+        for map in self.maps.iter() {
+            write!(f, "\n{map}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Almanac {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "seeds: {}", self.seeds.iter().join(" "))?;
+        for category_map in self.category_maps.iter() {
+            write!(f, "\n\n{category_map}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,214 @@
+use itertools::Itertools;
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use textwrap::dedent;
+
+use super::YEAR;
+use crate::config::Config;
+use crate::helpers::{parses_as, Example, Param, ParamValue, Params, Solution};
+use crate::utils::get_input_for_day;
+use crate::utils::math::quadratic_negative_range;
+use crate::utils::parse::{concat_digits, ints, values_for, ParseError};
+
+pub const PARAMS: [Param; 1] = [Param {
+    name: "accel",
+    default: ParamValue::Integer(1),
+    description: "Speed gained per millisecond the button is held",
+    parse: parses_as::<u64>,
+}];
+
+pub fn print_solutions_day6(config: &Config) {
+    let day: u32 = 6;
+    let example = Example {
+        input_data: dedent(
+            "
+            Time:      7  15   30
+            Distance:  9  40  200
+        ",
+        ),
+        expected_part1: 288,
+        expected_part2: 71503,
+        ..Default::default()
+    };
+    let solution = Solution {
+        input_data: get_input_for_day(&config.data_dir, YEAR, day),
+        get_solution_part1,
+        get_solution_part2,
+        params: &PARAMS,
+    };
+    solution.print_solutions(YEAR, day, example, config);
+}
+
+pub fn get_solution_part1(input_data_raw: &str, params: &Params) -> u64 {
+    let accel: u64 = params.get("accel");
+    let races: Races = input_data_raw.parse().unwrap();
+    let races = races
+        .races
+        .into_iter()
+        .map(|race| Race { accel, ..race })
+        .collect_vec();
+
+    get_solution(&races)
+}
+
+pub fn get_solution_part2(input_data_raw: &str, params: &Params) -> u64 {
+    // The spaces between numbers are bad kerning, so there's only one race
+    let race = Race {
+        time: concat_digits(get_values(input_data_raw, "Time").unwrap()).unwrap(),
+        distance: concat_digits(get_values(input_data_raw, "Distance").unwrap()).unwrap(),
+        accel: params.get("accel"),
+    };
+
+    get_solution(&[race])
+}
+
+/// Values from the line of input starting with the given key, e.g. "Time".
+fn get_values<'a>(input_data_raw: &'a str, key: &str) -> Result<&'a str, ParseError> {
+    input_data_raw
+        .lines()
+        .find_map(|line| values_for(line, key).ok())
+        .ok_or_else(|| ParseError::new(format!("No '{key}:' line in input")))
+}
+
+pub fn get_solution(races: &[Race]) -> u64 {
+    races
+        .iter()
+        .map(|r| r.count_winning_strategies())
+        .reduce(|x, y| x * y)
+        .unwrap_or(0)
+}
+
+/*
+Holding the button for x ms of a race gives a speed of accel * x, so the boat travels
+accel * x * (time - x) in the rest of the race. It has to go further than the record distance.
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Race {
+    pub time: u64,
+    pub distance: u64,
+    pub accel: u64,
+}
+
+/// Sheet of races, with a column of time and record distance for each race.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Races {
+    pub races: Vec<Race>,
+}
+
+/*
+e.g.
+    Time:      7  15   30
+    Distance:  9  40  200
+Races are parsed with the puzzle's acceleration of 1mm/ms for each ms the button is held.
+*/
+impl FromStr for Races {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let times: Vec<u64> = ints(get_values(s, "Time")?)?;
+        let distances: Vec<u64> = ints(get_values(s, "Distance")?)?;
+
+        if times.len() != distances.len() {
+            return Err(ParseError::new(format!(
+                "Found {} times but {} distances",
+                times.len(),
+                distances.len()
+            )));
+        }
+        let races = times
+            .into_iter()
+            .zip(distances)
+            .map(|(time, distance)| Race {
+                time,
+                distance,
+                accel: 1,
+            })
+            .collect();
+
+        Ok(Races { races })
+    }
+}
+
+// A sheet with a single race
+impl FromStr for Race {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let races: Races = s.parse()?;
+        match <[Race; 1]>::try_from(races.races) {
+            Ok([race]) => Ok(race),
+            Err(races) => Err(ParseError::new(format!(
+                "Expected 1 race but found {}",
+                races.len()
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for Races {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Right align each race's time and distance in a column
+        let widths = self
+            .races
+            .iter()
+            .map(|race| {
+                race.time
+                    .to_string()
+                    .len()
+                    .max(race.distance.to_string().len())
+            })
+            .collect_vec();
+
+        write!(f, "Time:    ")?;
+        for (race, width) in self.races.iter().zip(widths.iter()) {
+            write!(f, "  {:>width$}", race.time)?;
+        }
+        write!(f, "\nDistance:")?;
+        for (race, width) in self.races.iter().zip(widths.iter()) {
+            write!(f, "  {:>width$}", race.distance)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Race {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let races = Races {
+            races: vec![self.clone()],
+        };
+        write!(f, "{races}")
+    }
+}
+
+impl Race {
+    /// Whether holding the button for `press` ms beats the record.
+    pub fn wins(&self, press: u64) -> bool {
+        let Some(remaining) = self.time.checked_sub(press) else {
+            return false;
+        };
+        // Anything too big for a u128 is well past any u64 record
+        (self.accel as u128 * press as u128)
+            .checked_mul(remaining as u128)
+            .is_none_or(|travelled| travelled > self.distance as u128)
+    }
+
+    /*
+    Times the button can be held for to beat the record, or `None` if it can't be beaten.
+    As everything is an integer, beating the record needs x * (time - x) > distance / accel
+    rounded down, so the winning times are where x^2 - time * x + distance / accel < 0.
+    */
+    pub fn winning_press_times(&self) -> Option<RangeInclusive<u64>> {
+        if self.accel == 0 {
+            return None;
+        }
+        let range = quadratic_negative_range(1, -(self.time as i128), self.distance / self.accel)?;
+        // Both roots lie between 0 and time, so the range fits in a u64
+        Some(*range.start() as u64..=*range.end() as u64)
+    }
+
+    pub fn count_winning_strategies(&self) -> u64 {
+        self.winning_press_times()
+            .map_or(0, |range| range.end() - range.start() + 1)
+    }
+}
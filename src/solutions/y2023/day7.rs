@@ -1,12 +1,17 @@
 use itertools::Itertools;
 use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
 use textwrap::dedent;
 
-use crate::helpers::{Example, Solution};
+use super::YEAR;
+use crate::config::Config;
+use crate::helpers::{Example, Params, Solution};
 use crate::utils::get_input_for_day;
+use crate::utils::parse::ParseError;
 
-pub fn print_solutions_day7() {
+pub fn print_solutions_day7(config: &Config) {
     let day: u32 = 7;
     let example = Example {
         input_data: dedent(
@@ -20,21 +25,20 @@ pub fn print_solutions_day7() {
         ),
         expected_part1: 6440,
         expected_part2: 5905,
+        ..Default::default()
     };
     let solution = Solution {
-        input_data: get_input_for_day(day),
+        input_data: get_input_for_day(&config.data_dir, YEAR, day),
         get_solution_part1,
         get_solution_part2,
+        params: &[],
     };
-    solution.print_solutions(day, example);
+    solution.print_solutions(YEAR, day, example, config);
 }
 
-fn get_solution_part1(input_data_raw: &str) -> u64 {
+pub fn get_solution_part1(input_data_raw: &str, _params: &Params) -> u64 {
     let input_data = input_data_raw.trim().lines();
-    let mut hands: Vec<Hand> = input_data
-        .map(|line| line.split_whitespace().collect_tuple().unwrap())
-        .map(|(cards, bid)| Hand::create(cards, bid))
-        .collect_vec();
+    let mut hands: Vec<Hand> = input_data.map(|line| line.parse().unwrap()).collect_vec();
 
     hands.sort_by(Hand::compare);
     let solution: u32 = hands
@@ -47,13 +51,11 @@ fn get_solution_part1(input_data_raw: &str) -> u64 {
     solution as u64
 }
 
-fn get_solution_part2(input_data_raw: &str) -> u64 {
-    // Substitute Jacks (J) for Jokers (X)
-    let input_data_prepped = &input_data_raw.trim().replace("J", "X");
-    let mut hands: Vec<Hand> = input_data_prepped
-        .lines()
-        .map(|line| line.split_whitespace().collect_tuple().unwrap())
-        .map(|(cards, bid)| Hand::create(cards, bid))
+pub fn get_solution_part2(input_data_raw: &str, _params: &Params) -> u64 {
+    // Jacks (J) are now Jokers
+    let input_data = input_data_raw.trim().lines();
+    let mut hands: Vec<Hand> = input_data
+        .map(|line| line.parse::<Hand>().unwrap().with_jokers())
         .collect_vec();
 
     hands.sort_by(Hand::compare);
@@ -68,22 +70,33 @@ fn get_solution_part2(input_data_raw: &str) -> u64 {
     solution as u64
 }
 
-#[derive(Debug)]
-struct Hand {
-    cards: String,
-    bid: u32,
-    hand_type: HandType,
+// Cards from highest to lowest, apart from Jokers which go below '2'
+pub const CARD_ORDER: [char; 13] = [
+    'A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2',
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hand {
+    pub cards: String,
+    pub bid: u32,
+    /// Whether each 'J' is a Joker, standing in for whichever card makes the best hand.
+    pub jokers: bool,
+    pub hand_type: HandType,
 }
 
 impl Hand {
-    fn create(cards: &str, bid: &str) -> Hand {
+    pub fn new(cards: &str, bid: u32, jokers: bool) -> Hand {
         let mut card_freq_map: HashMap<char, u32> = cards
             .chars()
             .sorted()
             .into_grouping_map_by(|&x| x)
             .fold(0, |acc, _key, _value| acc + 1);
 
-        let num_jokers = card_freq_map.remove(&'X').unwrap_or(0);
+        let num_jokers = if jokers {
+            card_freq_map.remove(&'J').unwrap_or(0)
+        } else {
+            0
+        };
 
         // Convert to vector and sort by value descending
         let card_freqs: Vec<(char, u32)> = card_freq_map
@@ -95,12 +108,18 @@ impl Hand {
 
         Hand {
             cards: cards.to_string(),
-            bid: bid.parse().unwrap(),
+            bid,
+            jokers,
             hand_type,
         }
     }
 
-    fn _get_hand_type(card_freqs: &Vec<(char, u32)>, num_jokers: &u32) -> HandType {
+    /// The same hand, with its Jacks played as Jokers.
+    pub fn with_jokers(&self) -> Hand {
+        Hand::new(&self.cards, self.bid, true)
+    }
+
+    fn _get_hand_type(card_freqs: &[(char, u32)], num_jokers: &u32) -> HandType {
         let freqs: (u32, u32, u32, u32, u32) = card_freqs
             .iter()
             .map(|(_card, freq)| freq.to_owned())
@@ -133,7 +152,7 @@ impl Hand {
         }
     }
 
-    fn compare(x: &Hand, y: &Hand) -> Ordering {
+    pub fn compare(x: &Hand, y: &Hand) -> Ordering {
         let by_type = x.hand_type.cmp(&y.hand_type);
 
         if let Ordering::Equal = by_type {
@@ -144,18 +163,8 @@ impl Hand {
     }
 
     fn _compare_card_by_card(&self, other: &Hand) -> Ordering {
-        // Jokers represented by 'X' get the lowest order
-        let card_order = [
-            'A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2', '1', 'X',
-        ];
-        let card_order_map: HashMap<char, usize> = card_order
-            .iter()
-            .enumerate()
-            .map(|(i, card)| (card.clone(), i))
-            .collect();
-
         for (card1, card2) in self.cards.chars().zip(other.cards.chars()) {
-            let order = card_order_map.get(&card1).cmp(&card_order_map.get(&card2));
+            let order = self._card_order(card1).cmp(&other._card_order(card2));
 
             if let Ordering::Equal = order {
                 continue;
@@ -166,10 +175,21 @@ impl Hand {
 
         Ordering::Equal
     }
+
+    // Position in CARD_ORDER, with Jokers after every other card
+    fn _card_order(&self, card: char) -> usize {
+        if self.jokers && card == 'J' {
+            return CARD_ORDER.len();
+        }
+        CARD_ORDER
+            .iter()
+            .position(|&c| c == card)
+            .unwrap_or(CARD_ORDER.len())
+    }
 }
 
-#[derive(Debug, PartialOrd, Ord, PartialEq, Eq)]
-enum HandType {
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq)]
+pub enum HandType {
     FiveKind,
     FourKind,
     FullHouse,
@@ -178,3 +198,37 @@ enum HandType {
     OnePair,
     HighCard,
 }
+
+// e.g. "32T3K 765"
+impl FromStr for Hand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (cards, bid) = s
+            .split_whitespace()
+            .collect_tuple()
+            .ok_or_else(|| ParseError::new(format!("Expected 'cards bid' but found '{s}'")))?;
+
+        if cards.chars().count() != 5 {
+            return Err(ParseError::new(format!(
+                "Expected 5 cards but found '{cards}'"
+            )));
+        }
+        if let Some(card) = cards.chars().find(|card| !CARD_ORDER.contains(card)) {
+            return Err(ParseError::new(format!(
+                "Unknown card '{card}' in '{cards}'"
+            )));
+        }
+        let bid = bid
+            .parse()
+            .map_err(|_| ParseError::new(format!("Invalid bid '{bid}' in '{s}'")))?;
+
+        Ok(Hand::new(cards, bid, false))
+    }
+}
+
+impl fmt::Display for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.cards, self.bid)
+    }
+}
@@ -0,0 +1,264 @@
+use hashbrown::HashMap;
+use itertools::Itertools;
+use std::fmt;
+use std::iter::successors;
+use std::str::FromStr;
+use textwrap::dedent;
+
+use super::YEAR;
+use crate::config::Config;
+use crate::helpers::{parses_as, Example, Param, ParamValue, Params, Solution};
+use crate::utils::get_input_for_day;
+use crate::utils::graph::{find_cycle, Cycle};
+use crate::utils::math::crt;
+use crate::utils::parse::ParseError;
+
+pub const PARAMS: [Param; 4] = [
+    Param {
+        name: "start",
+        default: ParamValue::Text("AAA"),
+        description: "Suffix of the starting node in part 1",
+        parse: parses_as::<String>,
+    },
+    Param {
+        name: "target",
+        default: ParamValue::Text("ZZZ"),
+        description: "Suffix of the target node in part 1",
+        parse: parses_as::<String>,
+    },
+    Param {
+        name: "ghost_start",
+        default: ParamValue::Text("A"),
+        description: "Suffix of the ghosts' starting nodes in part 2",
+        parse: parses_as::<String>,
+    },
+    Param {
+        name: "ghost_target",
+        default: ParamValue::Text("Z"),
+        description: "Suffix of the ghosts' target nodes in part 2",
+        parse: parses_as::<String>,
+    },
+];
+
+pub fn print_solutions_day8(config: &Config) {
+    let day: u32 = 8;
+    let example = Example {
+        input_data: dedent(
+            "
+            LR
+
+            AAA = (11B, XXX)
+            11B = (XXX, ZZZ)
+            ZZZ = (11B, XXX)
+            22A = (22B, XXX)
+            22B = (22C, 22C)
+            22C = (22Z, 22Z)
+            22Z = (22B, 22B)
+            XXX = (XXX, XXX)
+        ",
+        ),
+        expected_part1: 2,
+        expected_part2: 6,
+        ..Default::default()
+    };
+    let solution = Solution {
+        input_data: get_input_for_day(&config.data_dir, YEAR, day),
+        get_solution_part1,
+        get_solution_part2,
+        params: &PARAMS,
+    };
+    solution.print_solutions(YEAR, day, example, config);
+}
+
+pub fn get_solution_part1(input_data_raw: &str, params: &Params) -> u64 {
+    let start: String = params.get("start");
+    let target: String = params.get("target");
+    get_nsteps_to_finish(input_data_raw, &start, &target)
+}
+
+pub fn get_solution_part2(input_data_raw: &str, params: &Params) -> u64 {
+    let start: String = params.get("ghost_start");
+    let target: String = params.get("ghost_target");
+    get_nsteps_to_finish(input_data_raw, &start, &target)
+}
+
+pub fn get_nsteps_to_finish(
+    input_data_raw: &str,
+    start_node_endswith: &str,
+    target_node_endswith: &str,
+) -> u64 {
+    // Parse input
+    let mut input_data = input_data_raw.trim().lines();
+    let instructions = input_data.next().unwrap().chars().collect_vec();
+
+    let all_nodes = input_data
+        .filter(|x| !x.is_empty())
+        .map(|x| x.parse::<Node>().unwrap())
+        .collect_vec();
+
+    // Number the nodes so the left and right of each can be looked up by index
+    let node_ids: HashMap<&str, usize> = all_nodes
+        .iter()
+        .enumerate()
+        .map(|(id, x)| (x.node.as_str(), id))
+        .collect();
+    let lefts = all_nodes
+        .iter()
+        .map(|x| node_ids[x.left.as_str()])
+        .collect_vec();
+    let rights = all_nodes
+        .iter()
+        .map(|x| node_ids[x.right.as_str()])
+        .collect_vec();
+    let is_target = all_nodes
+        .iter()
+        .map(|x| x.node.ends_with(target_node_endswith))
+        .collect_vec();
+
+    /*
+    A ghost's state is its node and how far it is through the instructions, so each state
+    leads to exactly one next state and every ghost ends up going round a cycle.
+    */
+    let one_step = |&(node, idx): &(usize, usize)| {
+        let next_node = match instructions[idx] {
+            'L' => lefts[node],
+            _ => rights[node],
+        };
+        (next_node, (idx + 1) % instructions.len())
+    };
+
+    // Ghosts start at the following nodes
+    let ghost_nodes = all_nodes
+        .iter()
+        .filter(|node| node.node.ends_with(start_node_endswith))
+        .collect_vec();
+
+    if ghost_nodes.is_empty() {
+        return 0;
+    }
+
+    let ghost_finishes = ghost_nodes
+        .iter()
+        .map(|ghost| {
+            let start = (node_ids[ghost.node.as_str()], 0);
+            let cycle = find_cycle(start, one_step);
+
+            // Steps where the ghost finishes, up to the end of its first time round the cycle
+            let finish_steps = successors(Some(start), |state| Some(one_step(state)))
+                .take(cycle.tail + cycle.period)
+                .positions(|(node, _)| is_target[node])
+                .filter(|&nstep| nstep > 0)
+                .collect_vec();
+            Finishes::new(&finish_steps, cycle)
+        })
+        .collect_vec();
+
+    // Until every ghost has reached its cycle, check each step directly
+    let all_cycling = ghost_finishes.iter().map(|x| x.tail).max().unwrap().max(1);
+    if let Some(nstep) = (1..all_cycling).find(|&nstep| ghost_finishes.iter().all(|x| x.at(nstep)))
+    {
+        return nstep as u64;
+    }
+
+    /*
+    After that, each ghost finishes whenever the number of steps is congruent to one of its
+    residues, so pick a residue for each ghost and solve for when they all line up.
+    */
+    let result = ghost_finishes
+        .iter()
+        .map(|x| x.residues.iter().map(|&residue| (residue, x.period as u64)))
+        .multi_cartesian_product()
+        .filter_map(crt)
+        .map(|(residue, modulus)| {
+            // First step from when they're all cycling with the combined residue
+            let all_cycling = all_cycling as u64;
+            if residue >= all_cycling {
+                residue
+            } else {
+                residue + (all_cycling - residue).div_ceil(modulus) * modulus
+            }
+        })
+        .min()
+        .expect("Ghosts never all finish at the same time");
+
+    return result;
+}
+
+/// Steps where a ghost is on a target node.
+struct Finishes {
+    /// Steps before the ghost reaches its cycle.
+    tail: usize,
+    tail_steps: Vec<usize>,
+    /// Once on its cycle, the ghost finishes at steps with these residues mod the period.
+    period: usize,
+    residues: Vec<u64>,
+}
+
+impl Finishes {
+    fn new(finish_steps: &[usize], cycle: Cycle) -> Finishes {
+        let (tail_steps, cycle_steps): (Vec<usize>, Vec<usize>) =
+            finish_steps.iter().partition(|&&nstep| nstep < cycle.tail);
+        Finishes {
+            tail: cycle.tail,
+            tail_steps,
+            period: cycle.period,
+            residues: cycle_steps
+                .iter()
+                .map(|nstep| (nstep % cycle.period) as u64)
+                .collect(),
+        }
+    }
+
+    fn at(&self, nstep: usize) -> bool {
+        if nstep < self.tail {
+            self.tail_steps.contains(&nstep)
+        } else {
+            self.residues.contains(&((nstep % self.period) as u64))
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    pub node: String,
+    pub left: String,
+    pub right: String,
+}
+
+// e.g. "AAA = (BBB, CCC)"
+impl FromStr for Node {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseError::new(format!("Expected 'AAA = (BBB, CCC)' but found '{s}'"));
+
+        let (node, left_right) = s.split_once('=').ok_or_else(invalid)?;
+        let (left, right) = left_right
+            .trim()
+            .strip_prefix('(')
+            .and_then(|x| x.strip_suffix(')'))
+            .and_then(|x| x.split_once(','))
+            .ok_or_else(invalid)?;
+
+        let (node, left, right) = (node.trim(), left.trim(), right.trim());
+        for name in [node, left, right] {
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric()) {
+                return Err(ParseError::new(format!(
+                    "Invalid node name '{name}' in '{s}'"
+                )));
+            }
+        }
+
+        Ok(Node {
+            node: node.to_owned(),
+            left: left.to_owned(),
+            right: right.to_owned(),
+        })
+    }
+}
+
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = ({}, {})", self.node, self.left, self.right)
+    }
+}
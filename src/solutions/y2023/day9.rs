@@ -0,0 +1,90 @@
+use textwrap::dedent;
+
+use super::YEAR;
+use crate::config::Config;
+use crate::helpers::{parses_as, Example, Param, ParamValue, Params, Solution};
+use crate::utils::parse::ints;
+use crate::utils::{diff, get_input_for_day};
+
+pub const PARAMS: [Param; 1] = [Param {
+    name: "max_depth",
+    default: ParamValue::Integer(1000),
+    description: "Most levels of differences to take",
+    parse: parses_as::<usize>,
+}];
+
+pub fn print_solutions_day9(config: &Config) {
+    let day: u32 = 9;
+    let example = Example {
+        input_data: dedent(
+            "
+            0 3 6 9 12 15
+            1 3 6 10 15 21
+            10 13 16 21 30 45
+        ",
+        ),
+        expected_part1: 114,
+        expected_part2: 2,
+        ..Default::default()
+    };
+    let solution = Solution {
+        input_data: get_input_for_day(&config.data_dir, YEAR, day),
+        get_solution_part1,
+        get_solution_part2,
+        params: &PARAMS,
+    };
+    solution.print_solutions(YEAR, day, example, config);
+}
+
+pub fn get_solution_part1(input_data_raw: &str, params: &Params) -> u64 {
+    get_solution(input_data_raw, false, params.get("max_depth"))
+}
+
+pub fn get_solution_part2(input_data_raw: &str, params: &Params) -> u64 {
+    get_solution(input_data_raw, true, params.get("max_depth"))
+}
+
+pub fn get_solution(input_data_raw: &str, backwards: bool, max_depth: usize) -> u64 {
+    let mut result = 0;
+    for line in input_data_raw.trim().lines() {
+        let values: Vec<i64> = ints(line).unwrap();
+        let next_val: i64 = if backwards {
+            get_previous_value(&values, max_depth)
+        } else {
+            get_next_value(&values, max_depth)
+        };
+        result += next_val
+    }
+    result as u64
+}
+
+/// Extrapolates the value after the end of the sequence.
+pub fn get_next_value(values: &[i64], max_depth: usize) -> i64 {
+    // Each level's next value is its last value plus the next value of the level below.
+    get_diff_levels(values, max_depth)
+        .iter()
+        .map(|level| level.last().unwrap_or(&0))
+        .sum()
+}
+
+/// Extrapolates the value before the start of the sequence.
+pub fn get_previous_value(values: &[i64], max_depth: usize) -> i64 {
+    // Each level's previous value is its first value minus the previous value of the level below.
+    get_diff_levels(values, max_depth)
+        .iter()
+        .rev()
+        .fold(0, |below, level| level.first().unwrap_or(&0) - below)
+}
+
+/// The values followed by their differences at each level, until they're all zero.
+fn get_diff_levels(values: &[i64], max_depth: usize) -> Vec<Vec<i64>> {
+    let mut levels: Vec<Vec<i64>> = vec![values.to_vec()];
+    for _ in 0..max_depth {
+        let prev_level = levels.last().unwrap();
+        if prev_level.iter().all(|&x| x == 0) {
+            break;
+        }
+        levels.push(diff(prev_level));
+    }
+    levels
+}
@@ -1,31 +1,70 @@
-use itertools::Itertools;
 use std::fs;
+use std::path::Path;
 
-pub fn get_first_char(line: &str) -> char {
-    return line.chars().nth(0).unwrap_or(' ');
-}
+pub mod diff;
+pub mod geom;
+pub mod graph;
+pub mod grid;
+pub mod interval;
+pub mod math;
+pub mod memo;
+pub mod parse;
 
-pub fn get_last_char(line: &str) -> char {
-    return line.chars().rev().nth(0).unwrap_or(' ');
-}
+pub use diff::{checked_diff, diff, diff_iter, diff_n, DiffMode};
+pub use geom::{Direction, Point, Vector};
+pub use grid::Grid;
+pub use interval::{Interval, IntervalSet, PiecewiseOffsetMap};
 
-pub fn get_input_for_day(day: u32) -> String {
-    let filepath: &str = &format!("data/input/day{day}.txt");
-    let input_data_raw: String = fs::read_to_string(filepath)
-        .expect(&format!("Should have been able to read file {filepath}"));
-    return input_data_raw;
+/// Occurrence of one of a set of patterns in a line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match<'p> {
+    /// Which of the patterns matched, as an index into the set.
+    pub pattern: usize,
+    pub token: &'p str,
+    /// Byte position in the line where the token starts.
+    pub position: usize,
 }
 
 /*
-Rolling difference between elements
-NOTE: this can result in overflow errors if say you provide a u32 and the
-difference becomes negative!
+Earliest occurrence of any of the patterns in a line, or `None` if there isn't one (e.g. the
+line is empty). Patterns can overlap, so in "eightwo" both "eight" and "two" are found.
+When several patterns start at the same place, the longest wins. Empty patterns are ignored.
 */
-pub fn diff<'a, T>(x: &'a Vec<T>) -> Vec<T>
-where
-    &'a T: std::ops::Sub<&'a T, Output = T>,
-{
-    let pairs: Vec<(&T, &T)> = x.iter().zip(x.iter().skip(1)).collect_vec();
-    let x_diff: Vec<T> = pairs.iter().map(|(curr, next)| *curr - *next).collect_vec();
-    x_diff
+pub fn find_first<'p, P: AsRef<str>>(line: &str, patterns: &'p [P]) -> Option<Match<'p>> {
+    line.char_indices()
+        .find_map(|(position, _)| match_at(line, position, patterns))
+}
+
+/// Occurrence of any of the patterns starting latest in a line, with the same rules as `find_first`.
+pub fn find_last<'p, P: AsRef<str>>(line: &str, patterns: &'p [P]) -> Option<Match<'p>> {
+    line.char_indices()
+        .rev()
+        .find_map(|(position, _)| match_at(line, position, patterns))
+}
+
+fn match_at<'p, P: AsRef<str>>(
+    line: &str,
+    position: usize,
+    patterns: &'p [P],
+) -> Option<Match<'p>> {
+    patterns
+        .iter()
+        .map(AsRef::as_ref)
+        .enumerate()
+        .filter(|(_, token)| !token.is_empty() && line[position..].starts_with(token))
+        .max_by_key(|(pattern, token)| (token.len(), std::cmp::Reverse(*pattern)))
+        .map(|(pattern, token)| Match {
+            pattern,
+            token,
+            position,
+        })
+}
+
+pub fn get_input_for_day(data_dir: &Path, year: u32, day: u32) -> String {
+    let filepath = data_dir
+        .join(year.to_string())
+        .join(format!("day{day}.txt"));
+    let input_data_raw: String = fs::read_to_string(&filepath)
+        .unwrap_or_else(|_| panic!("Should have been able to read file {}", filepath.display()));
+    return input_data_raw;
 }
@@ -0,0 +1,94 @@
+use itertools::Itertools;
+use num::{CheckedSub, Signed};
+use std::ops::Sub;
+
+/*
+Differences between consecutive elements of a sequence.
+
+The plain functions need a signed element type, so differences can go negative without
+wrapping, and asking for differences of e.g. a `&[u32]` doesn't compile. For unsigned types,
+or to catch overflow at the edges of a signed type, use the `checked_` versions instead.
+*/
+
+/// Which way round consecutive elements are subtracted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiffMode {
+    /// `x[i + 1] - x[i]`, how much the sequence changes going forward.
+    #[default]
+    Forward,
+    /// `x[i] - x[i + 1]`, how much the sequence changes going backward from the end.
+    Backward,
+}
+
+/// Forward differences `x[i + 1] - x[i]`, one shorter than the input.
+pub fn diff<T>(x: &[T]) -> Vec<T>
+where
+    T: Signed,
+    for<'a> &'a T: Sub<&'a T, Output = T>,
+{
+    diff_with(x, DiffMode::Forward)
+}
+
+/// Differences between consecutive elements, subtracted according to `mode`.
+pub fn diff_with<T>(x: &[T], mode: DiffMode) -> Vec<T>
+where
+    T: Signed,
+    for<'a> &'a T: Sub<&'a T, Output = T>,
+{
+    x.iter()
+        .tuple_windows()
+        .map(|(curr, next)| match mode {
+            DiffMode::Forward => next - curr,
+            DiffMode::Backward => curr - next,
+        })
+        .collect()
+}
+
+/// Differences of order `n`, i.e. `diff` applied `n` times. Empty once `n` reaches the length.
+pub fn diff_n<T>(x: &[T], n: usize) -> Vec<T>
+where
+    T: Signed + Clone,
+    for<'a> &'a T: Sub<&'a T, Output = T>,
+{
+    let mut result = x.to_vec();
+    for _ in 0..n.min(x.len()) {
+        result = diff(&result);
+    }
+    result
+}
+
+/// Forward differences of any sequence of values, computed lazily.
+pub fn diff_iter<I, T>(x: I) -> impl Iterator<Item = T>
+where
+    I: IntoIterator<Item = T>,
+    T: Signed + Clone,
+{
+    x.into_iter()
+        .tuple_windows()
+        .map(|(curr, next)| next - curr)
+}
+
+/// Forward differences, or `None` if any of them doesn't fit in `T`, e.g. a negative `u32`.
+pub fn checked_diff<T: CheckedSub>(x: &[T]) -> Option<Vec<T>> {
+    checked_diff_with(x, DiffMode::Forward)
+}
+
+/// Differences subtracted according to `mode`, or `None` if any of them doesn't fit in `T`.
+pub fn checked_diff_with<T: CheckedSub>(x: &[T], mode: DiffMode) -> Option<Vec<T>> {
+    x.iter()
+        .tuple_windows()
+        .map(|(curr, next)| match mode {
+            DiffMode::Forward => next.checked_sub(curr),
+            DiffMode::Backward => curr.checked_sub(next),
+        })
+        .collect()
+}
+
+/// Differences of order `n`, or `None` if any of the intermediate differences don't fit in `T`.
+pub fn checked_diff_n<T: CheckedSub + Clone>(x: &[T], n: usize) -> Option<Vec<T>> {
+    let mut result = x.to_vec();
+    for _ in 0..n.min(x.len()) {
+        result = checked_diff(&result)?;
+    }
+    Some(result)
+}
@@ -0,0 +1,209 @@
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/*
+Position on a grid, addressed as (row, col) with (0, 0) at the top left.
+Coordinates are unsigned, so moving a point uses checked arithmetic and gives `None`
+when the result would fall off the top or left edge rather than wrapping around.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub row: usize,
+    pub col: usize,
+}
+
+/// Signed offset between two points, in rows and columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Vector {
+    pub row: isize,
+    pub col: isize,
+}
+
+/// One of the four orthogonal directions on a grid, with up towards row 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Point {
+    pub const fn new(row: usize, col: usize) -> Point {
+        Point { row, col }
+    }
+
+    /// Moves the point by a vector, or `None` if it would have a negative coordinate.
+    pub fn checked_add(self, vector: Vector) -> Option<Point> {
+        Some(Point {
+            row: self.row.checked_add_signed(vector.row)?,
+            col: self.col.checked_add_signed(vector.col)?,
+        })
+    }
+
+    /// Moves the point one step in a direction, or `None` if it would go past row or column 0.
+    pub fn step(self, direction: Direction) -> Option<Point> {
+        self.checked_add(direction.vector())
+    }
+
+    /// The up to 4 orthogonal neighbours of the point.
+    pub fn neighbours4(self) -> impl Iterator<Item = Point> {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(direction))
+    }
+
+    /// The up to 8 neighbours of the point, including diagonals.
+    pub fn neighbours8(self) -> impl Iterator<Item = Point> {
+        Vector::NEIGHBOURS_8
+            .into_iter()
+            .filter_map(move |vector| self.checked_add(vector))
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+
+    pub fn chebyshev(self, other: Point) -> usize {
+        self.row
+            .abs_diff(other.row)
+            .max(self.col.abs_diff(other.col))
+    }
+}
+
+impl Vector {
+    /// Offsets to all 8 neighbours of a point, including diagonals, row by row.
+    pub const NEIGHBOURS_8: [Vector; 8] = [
+        Vector::new(-1, -1),
+        Vector::new(-1, 0),
+        Vector::new(-1, 1),
+        Vector::new(0, -1),
+        Vector::new(0, 1),
+        Vector::new(1, -1),
+        Vector::new(1, 0),
+        Vector::new(1, 1),
+    ];
+
+    pub const fn new(row: isize, col: isize) -> Vector {
+        Vector { row, col }
+    }
+
+    pub fn manhattan(self) -> usize {
+        self.row.unsigned_abs() + self.col.unsigned_abs()
+    }
+
+    pub fn chebyshev(self) -> usize {
+        self.row.unsigned_abs().max(self.col.unsigned_abs())
+    }
+}
+
+impl Direction {
+    /// All directions, clockwise from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction::Up => Vector::new(-1, 0),
+            Direction::Right => Vector::new(0, 1),
+            Direction::Down => Vector::new(1, 0),
+            Direction::Left => Vector::new(0, -1),
+        }
+    }
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+
+    /// Turns a quarter turn clockwise.
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    /// Turns a quarter turn anticlockwise.
+    pub fn turn_left(self) -> Direction {
+        self.turn_right().opposite()
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((row, col): (usize, usize)) -> Point {
+        Point { row, col }
+    }
+}
+
+impl From<Point> for (usize, usize) {
+    fn from(point: Point) -> (usize, usize) {
+        (point.row, point.col)
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    /// Vector from `other` to `self`.
+    fn sub(self, other: Point) -> Vector {
+        Vector {
+            row: self.row as isize - other.row as isize,
+            col: self.col as isize - other.col as isize,
+        }
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Vector) -> Vector {
+        Vector::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.row, -self.col)
+    }
+}
+
+impl Mul<isize> for Vector {
+    type Output = Vector;
+
+    fn mul(self, scale: isize) -> Vector {
+        Vector::new(self.row * scale, self.col * scale)
+    }
+}
+
+impl From<Direction> for Vector {
+    fn from(direction: Direction) -> Vector {
+        direction.vector()
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
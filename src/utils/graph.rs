@@ -0,0 +1,303 @@
+use hashbrown::{HashMap, HashSet};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/*
+Graph given by the neighbours of each node, which lets the search functions below work on
+an explicit adjacency map or a closure that generates neighbours on the fly, e.g.
+    let graph = |&x: &u32| [x + 1, x * 2];
+    bfs(&graph, 1);
+*/
+pub trait Graph<N> {
+    fn neighbours(&self, node: &N) -> impl IntoIterator<Item = N>;
+}
+
+/// Graph where each edge has a cost to follow it.
+pub trait WeightedGraph<N, C> {
+    fn edges(&self, node: &N) -> impl IntoIterator<Item = (N, C)>;
+}
+
+impl<N, F, I> Graph<N> for F
+where
+    F: Fn(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    fn neighbours(&self, node: &N) -> impl IntoIterator<Item = N> {
+        self(node)
+    }
+}
+
+impl<N: Clone + Eq + Hash> Graph<N> for HashMap<N, Vec<N>> {
+    fn neighbours(&self, node: &N) -> impl IntoIterator<Item = N> {
+        self.get(node).into_iter().flatten().cloned()
+    }
+}
+
+impl<N, C, F, I> WeightedGraph<N, C> for F
+where
+    F: Fn(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    fn edges(&self, node: &N) -> impl IntoIterator<Item = (N, C)> {
+        self(node)
+    }
+}
+
+impl<N: Clone + Eq + Hash, C: Clone> WeightedGraph<N, C> for HashMap<N, Vec<(N, C)>> {
+    fn edges(&self, node: &N) -> impl IntoIterator<Item = (N, C)> {
+        self.get(node).into_iter().flatten().cloned()
+    }
+}
+
+/// Where a functional graph (each node has exactly one successor) starts repeating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// Steps taken before first entering the cycle.
+    pub tail: usize,
+    /// Length of the cycle.
+    pub period: usize,
+}
+
+/// Number of edges to every node reachable from the start.
+pub fn bfs<N, G>(graph: &G, start: N) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    G: Graph<N>,
+{
+    let mut distances: HashMap<N, usize> = HashMap::from([(start.clone(), 0)]);
+    let mut queue: VecDeque<N> = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        let distance = distances[&node];
+        for next in graph.neighbours(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+/// Path with the fewest edges from the start to a node satisfying `is_goal`, including both ends.
+pub fn bfs_path<N, G, P>(graph: &G, start: N, is_goal: P) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    G: Graph<N>,
+    P: Fn(&N) -> bool,
+{
+    let mut parents: HashMap<N, Option<N>> = HashMap::from([(start.clone(), None)]);
+    let mut queue: VecDeque<N> = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(build_path(&parents, node));
+        }
+        for next in graph.neighbours(&node) {
+            if !parents.contains_key(&next) {
+                parents.insert(next.clone(), Some(node.clone()));
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/*
+Nodes reachable from the start in depth-first order, visiting neighbours in the order
+they're given. This is an order of visiting, not a path: a dead end off to one side is
+visited before the search backs out of it.
+*/
+pub fn dfs<N, G>(graph: &G, start: N) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+    G: Graph<N>,
+{
+    let mut visited: HashSet<N> = HashSet::new();
+    let mut order: Vec<N> = vec![];
+    let mut stack: Vec<N> = vec![start];
+
+    while let Some(node) = stack.pop() {
+        if !visited.insert(node.clone()) {
+            continue;
+        }
+        let neighbours: Vec<N> = graph.neighbours(&node).into_iter().collect();
+        stack.extend(
+            neighbours
+                .into_iter()
+                .rev()
+                .filter(|x| !visited.contains(x)),
+        );
+        order.push(node);
+    }
+    order
+}
+
+/// Cheapest path from the start to a node satisfying `is_goal`, along with its cost.
+pub fn dijkstra<N, C, G, P>(graph: &G, start: N, is_goal: P) -> Option<(C, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    G: WeightedGraph<N, C>,
+    P: Fn(&N) -> bool,
+{
+    astar(graph, start, is_goal, |_| C::default())
+}
+
+/*
+Cheapest path from the start to a node satisfying `is_goal`, guided by a heuristic.
+The heuristic must never overestimate the remaining cost for the path to be the cheapest.
+*/
+pub fn astar<N, C, G, P, H>(graph: &G, start: N, is_goal: P, heuristic: H) -> Option<(C, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    G: WeightedGraph<N, C>,
+    P: Fn(&N) -> bool,
+    H: Fn(&N) -> C,
+{
+    // Nodes are numbered as they're found so the queue doesn't need them to be ordered
+    let mut ids: HashMap<N, usize> = HashMap::from([(start.clone(), 0)]);
+    let mut nodes: Vec<N> = vec![start.clone()];
+    let mut costs: Vec<C> = vec![C::default()];
+    let mut parents: Vec<Option<usize>> = vec![None];
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), 0))]);
+
+    while let Some(Reverse((estimate, id))) = queue.pop() {
+        let cost = costs[id];
+        // Skip entries superseded by a cheaper path
+        if estimate > cost + heuristic(&nodes[id]) {
+            continue;
+        }
+        if is_goal(&nodes[id]) {
+            let mut path = vec![nodes[id].clone()];
+            let mut current = id;
+            while let Some(parent) = parents[current] {
+                path.push(nodes[parent].clone());
+                current = parent;
+            }
+            path.reverse();
+            return Some((cost, path));
+        }
+
+        let node = nodes[id].clone();
+        for (next, edge_cost) in graph.edges(&node) {
+            let next_cost = cost + edge_cost;
+            let next_id = match ids.get(&next) {
+                Some(&next_id) if costs[next_id] <= next_cost => continue,
+                Some(&next_id) => next_id,
+                None => {
+                    ids.insert(next.clone(), nodes.len());
+                    nodes.push(next);
+                    costs.push(next_cost);
+                    parents.push(None);
+                    nodes.len() - 1
+                }
+            };
+            costs[next_id] = next_cost;
+            parents[next_id] = Some(id);
+            queue.push(Reverse((next_cost + heuristic(&nodes[next_id]), next_id)));
+        }
+    }
+    None
+}
+
+/*
+Orders the nodes reachable from `roots` so every node comes before its neighbours,
+or `None` if there's a cycle. Longest paths in a DAG can be found by relaxing nodes
+in this order.
+*/
+pub fn topological_sort<N, G, R>(graph: &G, roots: R) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    G: Graph<N>,
+    R: IntoIterator<Item = N>,
+{
+    // Nodes still on the stack are in progress, so reaching one again means a cycle
+    let mut in_progress: HashSet<N> = HashSet::new();
+    let mut finished: HashSet<N> = HashSet::new();
+    let mut order: Vec<N> = vec![];
+
+    for root in roots {
+        if finished.contains(&root) {
+            continue;
+        }
+        let neighbours: Vec<N> = graph.neighbours(&root).into_iter().collect();
+        let mut stack: Vec<(N, std::vec::IntoIter<N>)> =
+            vec![(root.clone(), neighbours.into_iter())];
+        in_progress.insert(root);
+
+        while let Some((node, neighbours)) = stack.last_mut() {
+            match neighbours.next() {
+                Some(next) if in_progress.contains(&next) => return None,
+                Some(next) if finished.contains(&next) => {}
+                Some(next) => {
+                    let next_neighbours: Vec<N> = graph.neighbours(&next).into_iter().collect();
+                    in_progress.insert(next.clone());
+                    stack.push((next, next_neighbours.into_iter()));
+                }
+                None => {
+                    let node = node.clone();
+                    stack.pop();
+                    in_progress.remove(&node);
+                    finished.insert(node.clone());
+                    order.push(node);
+                }
+            }
+        }
+    }
+    order.reverse();
+    Some(order)
+}
+
+/*
+Finds the cycle reached by repeatedly applying `next` from the start, using Brent's
+algorithm so only a couple of nodes are held at a time.
+https://en.wikipedia.org/wiki/Cycle_detection#Brent's_algorithm
+*/
+pub fn find_cycle<N, F>(start: N, next: F) -> Cycle
+where
+    N: Clone + Eq,
+    F: Fn(&N) -> N,
+{
+    // Find the period by moving the hare in doubling blocks until it meets the tortoise
+    let mut power: usize = 1;
+    let mut period: usize = 1;
+    let mut tortoise = start.clone();
+    let mut hare = next(&start);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = next(&hare);
+        period += 1;
+    }
+
+    // With the hare a period ahead, they first meet where the cycle starts
+    let mut tortoise = start.clone();
+    let mut hare = start;
+    for _ in 0..period {
+        hare = next(&hare);
+    }
+    let mut tail: usize = 0;
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        tail += 1;
+    }
+
+    Cycle { tail, period }
+}
+
+fn build_path<N: Clone + Eq + Hash>(parents: &HashMap<N, Option<N>>, end: N) -> Vec<N> {
+    let mut path = vec![end];
+    while let Some(Some(parent)) = parents.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+    path
+}
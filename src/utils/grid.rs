@@ -0,0 +1,271 @@
+use itertools::Itertools;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use super::geom::Point;
+
+/*
+Dense 2D grid for puzzles laid out as a block of characters.
+Cells are stored row by row and addressed by (row, col), with (0, 0) at the top left.
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    n_rows: usize,
+    n_cols: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(n_rows: usize, n_cols: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![fill; n_rows * n_cols],
+            n_rows,
+            n_cols,
+        }
+    }
+
+    /// Builds a grid from its rows, which must all be the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>, String> {
+        let n_rows = rows.len();
+        let n_cols = rows.first().map_or(0, |row| row.len());
+
+        if let Some(row_idx) = rows.iter().position(|row| row.len() != n_cols) {
+            let len = rows[row_idx].len();
+            return Err(format!(
+                "Row {row_idx} has {len} columns, expected {n_cols} like the first row"
+            ));
+        }
+
+        let cells = rows.into_iter().flatten().collect();
+        Ok(Grid {
+            cells,
+            n_rows,
+            n_cols,
+        })
+    }
+
+    /// Parses a block of text into a grid, converting each character with `f`.
+    /// Blank lines around the block are ignored.
+    pub fn parse_with<F>(input: &str, f: F) -> Result<Grid<T>, String>
+    where
+        F: Fn(char) -> T,
+    {
+        let lines = input.lines().collect_vec();
+        let is_blank = |line: &&str| line.trim().is_empty();
+        let start = lines.iter().position(|line| !is_blank(line)).unwrap_or(0);
+        let end = lines
+            .iter()
+            .rposition(|line| !is_blank(line))
+            .map_or(0, |i| i + 1);
+
+        let rows = lines[start..end.max(start)]
+            .iter()
+            .map(|line| line.chars().map(&f).collect())
+            .collect();
+        Grid::from_rows(rows)
+    }
+
+    pub fn n_rows(&self) -> usize {
+        self.n_rows
+    }
+
+    pub fn n_cols(&self) -> usize {
+        self.n_cols
+    }
+
+    pub fn contains(&self, row: usize, col: usize) -> bool {
+        row < self.n_rows && col < self.n_cols
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if self.contains(row, col) {
+            self.cells.get(row * self.n_cols + col)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if self.contains(row, col) {
+            self.cells.get_mut(row * self.n_cols + col)
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.n_cols..(row + 1) * self.n_cols]
+    }
+
+    pub fn col(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        (0..self.n_rows).map(move |row| &self[(row, col)])
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + '_ {
+        (0..self.n_rows).map(|row| self.row(row))
+    }
+
+    pub fn cols(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T>> + '_ {
+        (0..self.n_cols).map(|col| self.col(col))
+    }
+
+    /// Iterates over all cells row by row, along with their (row, col) coordinates.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(idx, value)| ((idx / self.n_cols, idx % self.n_cols), value))
+    }
+
+    /// Coordinates of the up to 4 orthogonal neighbours of a cell that are inside the grid.
+    pub fn neighbours4(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Point::new(row, col)
+            .neighbours4()
+            .filter(|point| self.contains(point.row, point.col))
+            .map(<(usize, usize)>::from)
+    }
+
+    /// Coordinates of the up to 8 neighbours of a cell, including diagonals, that are inside the grid.
+    pub fn neighbours8(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Point::new(row, col)
+            .neighbours8()
+            .filter(|point| self.contains(point.row, point.col))
+            .map(<(usize, usize)>::from)
+    }
+
+    /// Coordinates of all cells matching a predicate, row by row.
+    pub fn find_all<'a, P>(&'a self, predicate: P) -> impl Iterator<Item = (usize, usize)> + 'a
+    where
+        P: Fn(&T) -> bool + 'a,
+    {
+        self.iter()
+            .filter(move |(_, value)| predicate(value))
+            .map(|(coords, _)| coords)
+    }
+
+    /// Coordinates of the first cell matching a predicate, row by row.
+    pub fn position<P>(&self, predicate: P) -> Option<(usize, usize)>
+    where
+        P: Fn(&T) -> bool,
+    {
+        self.find_all(predicate).next()
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: Fn(&T) -> U,
+    {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            n_rows: self.n_rows,
+            n_cols: self.n_cols,
+        }
+    }
+
+    /// Swaps rows and columns, so (row, col) moves to (col, row).
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..self.n_cols)
+            .flat_map(|col| self.col(col).cloned())
+            .collect();
+        Grid {
+            cells,
+            n_rows: self.n_cols,
+            n_cols: self.n_rows,
+        }
+    }
+
+    /// Rotates the grid a quarter turn clockwise.
+    pub fn rotate_clockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..self.n_cols)
+            .flat_map(|col| self.col(col).rev().cloned())
+            .collect();
+        Grid {
+            cells,
+            n_rows: self.n_cols,
+            n_cols: self.n_rows,
+        }
+    }
+
+    /// Rotates the grid a quarter turn anticlockwise.
+    pub fn rotate_anticlockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..self.n_cols)
+            .rev()
+            .flat_map(|col| self.col(col).cloned())
+            .collect();
+        Grid {
+            cells,
+            n_rows: self.n_cols,
+            n_cols: self.n_rows,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.get(row, col).unwrap_or_else(|| {
+            let (n_rows, n_cols) = (self.n_rows, self.n_cols);
+            panic!("({row}, {col}) is outside of the {n_rows}x{n_cols} grid")
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        let (n_rows, n_cols) = (self.n_rows, self.n_cols);
+        self.get_mut(row, col)
+            .unwrap_or_else(|| panic!("({row}, {col}) is outside of the {n_rows}x{n_cols} grid"))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        &self[(point.row, point.col)]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        &mut self[(point.row, point.col)]
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse_with(s, |c| c)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (row_idx, row) in self.rows().enumerate() {
+            if row_idx > 0 {
+                writeln!(f)?;
+            }
+            for value in row {
+                write!(f, "{value}")?;
+            }
+        }
+        Ok(())
+    }
+}
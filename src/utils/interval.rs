@@ -0,0 +1,240 @@
+use itertools::Itertools;
+use std::ops::{Add, Sub};
+
+/// Half-open interval of values `start..end`, empty when `start >= end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+/*
+Set of values stored as sorted, disjoint intervals.
+Intervals are coalesced as they're added, so there are no empty intervals and no two
+intervals overlap or touch, which makes two sets with the same values compare equal.
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+/*
+Function that shifts each value by the offset of the piece it falls in, leaving values
+outside all of the pieces unchanged. Pieces are kept sorted and must not overlap.
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PiecewiseOffsetMap<T> {
+    pieces: Vec<(Interval<T>, T)>,
+}
+
+impl<T: Ord + Copy> Interval<T> {
+    pub fn new(start: T, end: T) -> Interval<T> {
+        Interval { start, end }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Overlap of two intervals, or `None` if they don't share any values.
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        let overlap = Interval::new(self.start.max(other.start), self.end.min(other.end));
+        (!overlap.is_empty()).then_some(overlap)
+    }
+
+    /// Number of values in the interval, zero when it's empty.
+    pub fn len(&self) -> T
+    where
+        T: Sub<Output = T> + Default,
+    {
+        if self.is_empty() {
+            T::default()
+        } else {
+            self.end - self.start
+        }
+    }
+
+    /// Moves both ends of the interval by an offset.
+    pub fn shift(&self, offset: T) -> Interval<T>
+    where
+        T: Add<Output = T>,
+    {
+        Interval::new(self.start + offset, self.end + offset)
+    }
+}
+
+impl<T: Ord + Copy> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet { intervals: vec![] }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The disjoint intervals making up the set, in order.
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let idx = self.intervals.partition_point(|x| x.end <= value);
+        self.intervals.get(idx).is_some_and(|x| x.contains(value))
+    }
+
+    /// Smallest value in the set.
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|x| x.start)
+    }
+
+    /// Adds an interval, merging it with any intervals it overlaps or touches.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        // Intervals before `first` end before the new one starts, and intervals from `last`
+        // start after it ends, so anything in between is merged into the new interval
+        let first = self.intervals.partition_point(|x| x.end < interval.start);
+        let last = self.intervals.partition_point(|x| x.start <= interval.end);
+
+        let mut merged = interval;
+        if first < last {
+            merged.start = merged.start.min(self.intervals[first].start);
+            merged.end = merged.end.max(self.intervals[last - 1].end);
+        }
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        self.intervals
+            .iter()
+            .merge(other.intervals.iter())
+            .copied()
+            .collect()
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        // Walk both sets in order, advancing whichever interval ends first
+        let mut intervals = vec![];
+        let (mut i, mut j) = (0, 0);
+        while let (Some(x), Some(y)) = (self.intervals.get(i), other.intervals.get(j)) {
+            if let Some(overlap) = x.intersection(y) {
+                intervals.push(overlap);
+            }
+            if x.end <= y.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    /// Values in this set that aren't in the other.
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals = vec![];
+        let mut others = other.intervals.iter().peekable();
+
+        for interval in self.intervals.iter() {
+            let mut start = interval.start;
+            // Skip intervals that end before this one starts
+            while others.next_if(|x| x.end <= start).is_some() {}
+
+            // Cut out each interval that overlaps, keeping the part before it
+            while let Some(cut) = others.peek().filter(|x| x.start < interval.end) {
+                if start < cut.start {
+                    intervals.push(Interval::new(start, cut.start));
+                }
+                start = start.max(cut.end);
+                if cut.end > interval.end {
+                    break;
+                }
+                others.next();
+            }
+            if start < interval.end {
+                intervals.push(Interval::new(start, interval.end));
+            }
+        }
+        IntervalSet { intervals }
+    }
+}
+
+impl<T: Ord + Copy> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet::new()
+    }
+}
+
+impl<T: Ord + Copy> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+impl<T: Ord + Copy> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        IntervalSet::from_iter([interval])
+    }
+}
+
+impl<T> PiecewiseOffsetMap<T>
+where
+    T: Ord + Copy + Add<Output = T>,
+{
+    /// Builds a map from (interval, offset) pieces, ignoring empty intervals.
+    pub fn new<I>(pieces: I) -> Result<PiecewiseOffsetMap<T>, String>
+    where
+        I: IntoIterator<Item = (Interval<T>, T)>,
+        T: std::fmt::Debug,
+    {
+        let pieces = pieces
+            .into_iter()
+            .filter(|(interval, _)| !interval.is_empty())
+            .sorted_by_key(|(interval, _)| interval.start)
+            .collect_vec();
+
+        if let Some(((x, _), (y, _))) = pieces
+            .iter()
+            .tuple_windows()
+            .find(|((x, _), (y, _))| x.end > y.start)
+        {
+            return Err(format!("Intervals {x:?} and {y:?} overlap"));
+        }
+        Ok(PiecewiseOffsetMap { pieces })
+    }
+
+    pub fn pieces(&self) -> &[(Interval<T>, T)] {
+        &self.pieces
+    }
+
+    pub fn map_value(&self, value: T) -> T {
+        let idx = self.pieces.partition_point(|(x, _)| x.end <= value);
+        match self.pieces.get(idx) {
+            Some((interval, offset)) if interval.contains(value) => value + *offset,
+            _ => value,
+        }
+    }
+
+    /// Maps every value in a set, splitting intervals where they cross between pieces.
+    pub fn map_set(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        let domain: IntervalSet<T> = self.pieces.iter().map(|(x, _)| *x).collect();
+
+        let shifted = self.pieces.iter().flat_map(|(interval, offset)| {
+            set.intersection(&IntervalSet::from(*interval))
+                .intervals
+                .into_iter()
+                .map(move |x| x.shift(*offset))
+        });
+        let unchanged = set.difference(&domain).intervals;
+
+        shifted.chain(unchanged).collect()
+    }
+}
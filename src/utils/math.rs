@@ -0,0 +1,243 @@
+use num::{BigInt, BigRational, One, Signed, ToPrimitive, Zero};
+use std::ops::RangeInclusive;
+
+/*
+Polynomial with rational coefficients, stored lowest power first, e.g. [1, 0, 3] is 1 + 3x^2.
+Trailing zero coefficients are dropped, so the zero polynomial has no coefficients.
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial {
+    coefficients: Vec<BigRational>,
+}
+
+/*
+Combines congruences x = a (mod m) into a single congruence x = a (mod lcm of the m's).
+The moduli don't need to be coprime, so this gives `None` when the congruences can't
+all hold at once, or when the combined modulus doesn't fit in a u64.
+https://en.wikipedia.org/wiki/Chinese_remainder_theorem#Generalization_to_non-coprime_moduli
+*/
+pub fn crt<I>(congruences: I) -> Option<(u64, u64)>
+where
+    I: IntoIterator<Item = (u64, u64)>,
+{
+    let mut combined: (u64, u64) = (0, 1);
+
+    for (residue, modulus) in congruences {
+        if modulus == 0 {
+            return None;
+        }
+        let (a1, m1) = combined;
+        let (a2, m2) = (residue % modulus, modulus);
+
+        // Need a1 + m1 * k = a2 (mod m2), which is only solvable if gcd(m1, m2) divides a2 - a1
+        let (g, m1_inverse, _) = extended_gcd(m1 as i128, m2 as i128);
+        let difference = a2 as i128 - a1 as i128;
+        if difference % g != 0 {
+            return None;
+        }
+        let lcm = u64::try_from(m1 as u128 / g as u128 * m2 as u128).ok()?;
+
+        // k = (a2 - a1) / g * inverse of m1 / g, working mod m2 / g
+        let n = m2 as i128 / g;
+        let k = (difference / g).rem_euclid(n) as u128 * m1_inverse.rem_euclid(n) as u128;
+        let k = (k % n as u128) as u64;
+
+        // m1 * k < lcm, so this fits in a u64
+        combined = ((a1 as u128 + m1 as u128 * k as u128) as u64 % lcm, lcm);
+    }
+    Some(combined)
+}
+
+/// Largest integer whose square is at most `n`.
+pub fn isqrt_u64(n: u64) -> u64 {
+    isqrt_u128(n as u128) as u64
+}
+
+/// Largest integer whose square is at most `n`.
+pub fn isqrt_u128(n: u128) -> u128 {
+    // The float estimate loses precision once n is above 2^52, so refine it with a Newton
+    // step, which leaves it at most 1 out either way
+    let mut root = (n as f64).sqrt() as u128;
+    if root > 0 {
+        root = (root + n / root) / 2;
+    }
+    while root.checked_mul(root).is_none_or(|square| square > n) {
+        root -= 1;
+    }
+    while (root + 1)
+        .checked_mul(root + 1)
+        .is_some_and(|square| square <= n)
+    {
+        root += 1;
+    }
+    root
+}
+
+/*
+Integers x where a*x^2 + b*x + c < 0, which is the range strictly between the two roots
+when a > 0, or `None` if there are no such integers.
+Works in integers throughout, so there's no rounding at the ends of the range.
+Coefficients can be anything from -u64::MAX to u64::MAX, so both i64 and u64 values fit.
+*/
+pub fn quadratic_negative_range(
+    a: impl Into<i128>,
+    b: impl Into<i128>,
+    c: impl Into<i128>,
+) -> Option<RangeInclusive<i128>> {
+    let (a, b, c) = (a.into(), b.into(), c.into());
+    assert!(a > 0, "Quadratic must open upwards, got a = {a}");
+    assert!(
+        [a, b, c]
+            .iter()
+            .all(|x| x.unsigned_abs() <= u64::MAX as u128),
+        "Coefficients must fit in 64 bits, got {a}, {b}, {c}"
+    );
+
+    // b^2 - 4ac can need 131 bits, so work it out as a BigInt. Its root still fits in an i128.
+    let discriminant = BigInt::from(b).pow(2) - BigInt::from(4 * a) * BigInt::from(c);
+    if !discriminant.is_positive() {
+        return None;
+    }
+    let root = discriminant
+        .sqrt()
+        .to_i128()
+        .expect("Square root of the discriminant fits in an i128");
+
+    // Values far from the roots can overflow, but they're positive anyway
+    let is_negative = |x: i128| {
+        let value = a
+            .checked_mul(x)
+            .and_then(|ax| ax.checked_add(b))
+            .and_then(|axb| axb.checked_mul(x))
+            .and_then(|axbx| axbx.checked_add(c));
+        value.is_some_and(|value| value < 0)
+    };
+
+    /*
+    With the integer square root, (-b - root) / 2a is less than 1/2 above the lower root,
+    so the first integer past the lower root is either its floor or the next one up.
+    Likewise (-b + root) / 2a is less than 1/2 below the upper root, so the last integer
+    before the upper root is its floor or one either side (when the root is an integer).
+    */
+    let low = (-b - root).div_euclid(2 * a);
+    let low = if is_negative(low) { low } else { low + 1 };
+    let high = (-b + root).div_euclid(2 * a);
+    let high = if is_negative(high + 1) {
+        high + 1
+    } else if is_negative(high) {
+        high
+    } else {
+        high - 1
+    };
+
+    (low <= high && is_negative(low)).then_some(low..=high)
+}
+
+impl Polynomial {
+    pub fn new(coefficients: Vec<BigRational>) -> Polynomial {
+        let mut coefficients = coefficients;
+        while coefficients.last().is_some_and(|x| x.is_zero()) {
+            coefficients.pop();
+        }
+        Polynomial { coefficients }
+    }
+
+    /// Coefficients from the constant term up.
+    pub fn coefficients(&self) -> &[BigRational] {
+        &self.coefficients
+    }
+
+    /// Highest power with a non-zero coefficient, or `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    pub fn eval(&self, x: &BigRational) -> BigRational {
+        // Horner's method
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(BigRational::zero(), |acc, coefficient| {
+                acc * x + coefficient
+            })
+    }
+
+    /*
+    Lowest degree polynomial passing through all the points, built from Newton's divided
+    differences. Fails if two points have the same x.
+    https://en.wikipedia.org/wiki/Newton_polynomial
+    */
+    pub fn interpolate(points: &[(i64, i64)]) -> Result<Polynomial, String> {
+        check_distinct_xs(points)?;
+        let xs = points.iter().map(|&(x, _)| ratio(x)).collect::<Vec<_>>();
+
+        // Divided differences, updated in place so entry i ends up as f[x_0, ..., x_i]
+        let mut differences = points.iter().map(|&(_, y)| ratio(y)).collect::<Vec<_>>();
+        for order in 1..points.len() {
+            for i in (order..points.len()).rev() {
+                differences[i] =
+                    (&differences[i] - &differences[i - 1]) / (&xs[i] - &xs[i - order]);
+            }
+        }
+
+        // Expand the Newton form from the innermost term out:
+        // p(x) = d_0 + (x - x_0)(d_1 + (x - x_1)(d_2 + ...))
+        let mut coefficients: Vec<BigRational> = vec![];
+        for i in (0..points.len()).rev() {
+            // Multiply by (x - x_i), then add d_i
+            let mut shifted = vec![BigRational::zero(); coefficients.len() + 1];
+            for (power, coefficient) in coefficients.iter().enumerate() {
+                shifted[power + 1] += coefficient;
+                shifted[power] -= coefficient * &xs[i];
+            }
+            shifted[0] += &differences[i];
+            coefficients = shifted;
+        }
+        Ok(Polynomial::new(coefficients))
+    }
+}
+
+/*
+Value at x of the lowest degree polynomial through the points, from the Lagrange form.
+Fails if two points have the same x.
+https://en.wikipedia.org/wiki/Lagrange_polynomial
+*/
+pub fn lagrange_eval(points: &[(i64, i64)], x: i64) -> Result<BigRational, String> {
+    check_distinct_xs(points)?;
+    let x = ratio(x);
+
+    let mut value = BigRational::zero();
+    for (i, &(x_i, y_i)) in points.iter().enumerate() {
+        let mut basis = BigRational::one();
+        for (j, &(x_j, _)) in points.iter().enumerate() {
+            if i != j {
+                basis *= (&x - ratio(x_j)) / (ratio(x_i) - ratio(x_j));
+            }
+        }
+        value += basis * ratio(y_i);
+    }
+    Ok(value)
+}
+
+fn check_distinct_xs(points: &[(i64, i64)]) -> Result<(), String> {
+    for (i, &(x, _)) in points.iter().enumerate() {
+        if points[..i].iter().any(|&(other, _)| other == x) {
+            return Err(format!("Can't interpolate through two points with x = {x}"));
+        }
+    }
+    Ok(())
+}
+
+fn ratio(x: i64) -> BigRational {
+    BigRational::from_integer(BigInt::from(x))
+}
+
+/// Returns (g, s, t) with g = gcd(a, b) = s * a + t * b.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, s, t) = extended_gcd(b, a % b);
+        (g, t, s - (a / b) * t)
+    }
+}
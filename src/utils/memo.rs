@@ -0,0 +1,183 @@
+use hashbrown::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/*
+Memoised recursive function. The function is given a handle to call itself through the
+cache, along with the state to solve for, e.g. counting paths through a grid:
+    let mut paths = Memo::new(|paths, (row, col): (usize, usize)| {
+        if row == 0 || col == 0 { 1 } else { paths((row - 1, col)) + paths((row, col - 1)) }
+    });
+    paths.get((16, 16));
+States are cached in a hash map by default, or in a `DenseTable` for small integer states.
+*/
+pub struct Memo<K, V, F, C = HashMap<K, V>> {
+    cache: C,
+    f: F,
+    stats: CacheStats,
+    state: PhantomData<fn(K) -> V>,
+}
+
+/// Storage for the values a `Memo` has already worked out.
+pub trait Cache<K, V> {
+    fn lookup(&self, key: &K) -> Option<&V>;
+    fn store(&mut self, key: K, value: V);
+}
+
+/*
+Cache for states made up of D small integers, stored in a flat table rather than hashed.
+Each part of a state must be less than the size given for that dimension.
+*/
+#[derive(Debug, Clone)]
+pub struct DenseTable<V, const D: usize> {
+    dims: [usize; D],
+    values: Vec<Option<V>>,
+}
+
+/// How often a `Memo` found a state in its cache. Only counted in debug builds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl<K, V, F> Memo<K, V, F>
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+    F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+{
+    pub fn new(f: F) -> Memo<K, V, F> {
+        Memo::with_cache(HashMap::new(), f)
+    }
+}
+
+impl<V, F, const D: usize> Memo<[usize; D], V, F, DenseTable<V, D>>
+where
+    V: Clone,
+    F: Fn(&mut dyn FnMut([usize; D]) -> V, [usize; D]) -> V,
+{
+    /// Memoises over states `[x_1, ..., x_D]` with each `x_i < dims[i]`, using a `DenseTable`.
+    pub fn dense(dims: [usize; D], f: F) -> Memo<[usize; D], V, F, DenseTable<V, D>> {
+        Memo::with_cache(DenseTable::new(dims), f)
+    }
+}
+
+impl<K, V, F, C> Memo<K, V, F, C>
+where
+    K: Clone,
+    V: Clone,
+    F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+    C: Cache<K, V>,
+{
+    pub fn with_cache(cache: C, f: F) -> Memo<K, V, F, C> {
+        Memo {
+            cache,
+            f,
+            stats: CacheStats::default(),
+            state: PhantomData,
+        }
+    }
+
+    /// Value of the function for a state, working it out (and any states it needs) if required.
+    pub fn get(&mut self, key: K) -> V {
+        get_with(&mut self.cache, &mut self.stats, &self.f, key)
+    }
+
+    /// Cache hits and misses so far, or `None` in release builds where they aren't counted.
+    pub fn stats(&self) -> Option<CacheStats> {
+        cfg!(debug_assertions).then_some(self.stats)
+    }
+}
+
+/// Calls a memoised recursive function once, for when the cache isn't needed afterwards.
+pub fn memoize<K, V, F>(key: K, f: F) -> V
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+    F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+{
+    Memo::new(f).get(key)
+}
+
+// Kept separate from `Memo::get` so the cache and function can be borrowed independently
+fn get_with<K, V, F, C>(cache: &mut C, stats: &mut CacheStats, f: &F, key: K) -> V
+where
+    K: Clone,
+    V: Clone,
+    F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+    C: Cache<K, V>,
+{
+    if let Some(value) = cache.lookup(&key) {
+        if cfg!(debug_assertions) {
+            stats.hits += 1;
+        }
+        return value.clone();
+    }
+    if cfg!(debug_assertions) {
+        stats.misses += 1;
+    }
+
+    let value = f(&mut |next| get_with(cache, stats, f, next), key.clone());
+    cache.store(key, value.clone());
+    value
+}
+
+impl<K: Eq + Hash, V> Cache<K, V> for HashMap<K, V> {
+    fn lookup(&self, key: &K) -> Option<&V> {
+        self.get(key)
+    }
+
+    fn store(&mut self, key: K, value: V) {
+        self.insert(key, value);
+    }
+}
+
+impl<V, const D: usize> DenseTable<V, D> {
+    pub fn new(dims: [usize; D]) -> DenseTable<V, D> {
+        let size = dims.iter().product();
+        DenseTable {
+            dims,
+            values: (0..size).map(|_| None).collect(),
+        }
+    }
+
+    fn index(&self, key: &[usize; D]) -> usize {
+        key.iter().zip(self.dims.iter()).fold(0, |idx, (&x, &dim)| {
+            assert!(
+                x < dim,
+                "State {key:?} is outside the table of size {:?}",
+                self.dims
+            );
+            idx * dim + x
+        })
+    }
+}
+
+impl<V, const D: usize> Cache<[usize; D], V> for DenseTable<V, D> {
+    fn lookup(&self, key: &[usize; D]) -> Option<&V> {
+        self.values[self.index(key)].as_ref()
+    }
+
+    fn store(&mut self, key: [usize; D], value: V) {
+        let idx = self.index(&key);
+        self.values[idx] = Some(value);
+    }
+}
+
+impl fmt::Display for CacheStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let total = self.hits + self.misses;
+        let hit_rate = if total > 0 {
+            100. * self.hits as f64 / total as f64
+        } else {
+            0.
+        };
+        write!(
+            f,
+            "{} hits, {} misses ({hit_rate:.1}% hit rate)",
+            self.hits, self.misses
+        )
+    }
+}
@@ -0,0 +1,121 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Error from parsing puzzle input, describing what couldn't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> ParseError {
+        ParseError {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+/*
+Extracts all the integers in a line, ignoring any other text, e.g.
+"Card 3: -1 21 | 5" -> [3, -1, 21, 5].
+A '-' is only read as a sign when it directly precedes a digit and doesn't follow a letter
+or digit, so names like "seed-to-soil" or ranges like "1-3" aren't read as negative numbers.
+*/
+pub fn ints<T: FromStr>(line: &str) -> Result<Vec<T>, ParseError> {
+    int_tokens(line)
+        .map(|token| {
+            token
+                .parse()
+                .map_err(|_| ParseError::new(format!("Invalid integer '{token}' in '{line}'")))
+        })
+        .collect()
+}
+
+/// Concatenates all the digits in a line into a single number, e.g. "Time: 7 15 30" -> 71530.
+pub fn concat_digits<T: FromStr>(line: &str) -> Result<T, ParseError> {
+    let digits: String = line.chars().filter(char::is_ascii_digit).collect();
+    if digits.is_empty() {
+        return Err(ParseError::new(format!("No digits found in '{line}'")));
+    }
+    digits
+        .parse()
+        .map_err(|_| ParseError::new(format!("Number {digits} from '{line}' is out of range")))
+}
+
+/// Splits input into its sections separated by blank lines, ignoring empty sections.
+pub fn blocks(input: &str) -> Vec<&str> {
+    let mut blocks: Vec<&str> = vec![];
+    let mut block_start: Option<usize> = None;
+    let mut block_end: usize = 0;
+    let mut offset: usize = 0;
+
+    for line in input.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some(start) = block_start.take() {
+                blocks.push(input[start..block_end].trim_end());
+            }
+        } else {
+            block_start.get_or_insert(offset);
+            block_end = offset + line.len();
+        }
+        offset += line.len();
+    }
+    if let Some(start) = block_start {
+        blocks.push(input[start..block_end].trim_end());
+    }
+
+    blocks
+}
+
+/// Splits a "key: values" line at the first ':', trimming both sides.
+pub fn key_values(line: &str) -> Result<(&str, &str), ParseError> {
+    line.split_once(':')
+        .map(|(key, values)| (key.trim(), values.trim()))
+        .ok_or_else(|| ParseError::new(format!("Expected 'key: values' but found '{line}'")))
+}
+
+/// Gets the values from a "key: values" line, checking the key is the expected one.
+pub fn values_for<'a>(line: &'a str, key: &str) -> Result<&'a str, ParseError> {
+    let (found_key, values) = key_values(line)?;
+    if found_key != key {
+        return Err(ParseError::new(format!(
+            "Expected key '{key}' but found '{found_key}' in '{line}'"
+        )));
+    }
+    Ok(values)
+}
+
+/// Slices of a line that look like integers, with an optional leading '-'.
+fn int_tokens(line: &str) -> impl Iterator<Item = &str> {
+    let bytes = line.as_bytes();
+    let mut idx = 0;
+
+    std::iter::from_fn(move || {
+        while idx < bytes.len() {
+            let start = idx;
+            let follows_word = start > 0 && bytes[start - 1].is_ascii_alphanumeric();
+            let is_sign = bytes[idx] == b'-'
+                && !follows_word
+                && bytes.get(idx + 1).is_some_and(u8::is_ascii_digit);
+
+            if bytes[idx].is_ascii_digit() || is_sign {
+                idx += 1;
+                while idx < bytes.len() && bytes[idx].is_ascii_digit() {
+                    idx += 1;
+                }
+                return Some(&line[start..idx]);
+            }
+            idx += 1;
+        }
+        None
+    })
+}
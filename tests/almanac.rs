@@ -0,0 +1,264 @@
+/*
+Tests for day 5's almanac queries between categories.
+*/
+use proptest::prelude::*;
+
+use advent_of_code::solutions::y2023::day5::{
+    get_min_location, value_range, Almanac, CategoryMap, Map,
+};
+use advent_of_code::utils::{Interval, IntervalSet};
+
+const EXAMPLE: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+fn example() -> Almanac {
+    EXAMPLE.parse().unwrap()
+}
+
+// Maps with non-overlapping source ranges, given as "dest src len" rows
+fn category_map() -> impl Strategy<Value = Vec<String>> {
+    prop::collection::vec((0..100u64, 1..30u64, 0..100u64), 0..5).prop_map(|pieces| {
+        let mut pieces = pieces;
+        pieces.sort();
+        let mut rows = vec![];
+        let mut free_from = 0;
+        for (src, len, dest) in pieces {
+            let src = src.max(free_from);
+            rows.push(format!("{dest} {src} {len}"));
+            free_from = src + len;
+        }
+        rows
+    })
+}
+
+fn almanac() -> impl Strategy<Value = Almanac> {
+    let categories = ["seed", "soil", "water", "location"];
+    prop::collection::vec(category_map(), 3).prop_map(move |maps| {
+        let mut text = "seeds: 1".to_owned();
+        for (i, rows) in maps.iter().enumerate() {
+            text += &format!("\n\n{}-to-{} map:", categories[i], categories[i + 1]);
+            for row in rows {
+                text += &format!("\n{row}");
+            }
+        }
+        text.parse().unwrap()
+    })
+}
+
+#[test]
+fn maps_between_any_reachable_categories() {
+    let almanac = example();
+    assert_eq!(almanac.map_value(79, "seed", "soil"), Ok(81));
+    assert_eq!(almanac.map_value(79, "seed", "location"), Ok(82));
+    assert_eq!(almanac.map_value(81, "soil", "light"), Ok(74));
+    assert_eq!(almanac.map_value(5, "water", "water"), Ok(5));
+    assert_eq!(almanac.chain("seed", "location").unwrap().len(), 7);
+}
+
+#[test]
+fn rejects_missing_and_cyclic_chains() {
+    let almanac = example();
+    assert!(almanac.map_value(82, "location", "seed").is_err());
+    assert!(almanac.map_value(1, "seed", "fuel").is_err());
+
+    let cyclic: Almanac =
+        "seeds: 1\n\na-to-b map:\n0 0 5\n\nb-to-a map:\n0 0 5\n\nb-to-c map:\n0 0 5"
+            .parse()
+            .unwrap();
+    assert_eq!(
+        cyclic.map_value(1, "a", "c"),
+        Err("Maps from 'a' form a cycle".to_owned())
+    );
+    assert_eq!(
+        cyclic.map_value(1, "b", "c"),
+        Err("Maps from 'b' form a cycle".to_owned())
+    );
+}
+
+#[test]
+fn follows_branching_maps() {
+    let almanac: Almanac =
+        "seeds: 1\n\na-to-b map:\n10 0 5\n\na-to-c map:\n20 0 5\n\nb-to-c map:\n0 10 5"
+            .parse()
+            .unwrap();
+    // The direct map is the shortest chain
+    assert_eq!(almanac.map_value(1, "a", "c"), Ok(21));
+    assert_eq!(almanac.map_value(1, "b", "c"), Ok(1));
+}
+
+#[test]
+fn composes_the_example_maps() {
+    let almanac = example();
+    let composed = almanac.compose("seed", "location").unwrap();
+    assert_eq!(composed.apply(79), Ok(82));
+    assert_eq!(composed.apply(14), Ok(43));
+    assert_eq!(composed.inverse(46), vec![82]);
+    assert_eq!(composed.breakpoints()[0], 0);
+
+    let seeds = IntervalSet::from_iter([Interval::new(79, 93), Interval::new(55, 68)]);
+    assert_eq!(get_min_location(&[(79, 14), (55, 13)], &almanac), Ok(46));
+    assert_eq!(composed.min_over(&seeds), Ok(Some(46)));
+}
+
+#[test]
+fn handles_ranges_touching_u64_max() {
+    let max = u64::MAX;
+    let almanac: Almanac = format!(
+        "seeds: {} 10 5 1\n\nseed-to-location map:\n0 {} 10\n{} 0 10",
+        max - 9,
+        max - 9,
+        max - 9
+    )
+    .parse()
+    .unwrap();
+
+    // The top ten values swap places with the bottom ten
+    assert_eq!(almanac.map_value(max, "seed", "location"), Ok(9));
+    assert_eq!(almanac.map_value(3, "seed", "location"), Ok(max - 6));
+    assert_eq!(
+        get_min_location(&almanac.seed_ranges().unwrap(), &almanac),
+        Ok(0)
+    );
+    assert_eq!(get_min_location(&[(5, 1)], &almanac), Ok(max - 4));
+
+    let composed = almanac.compose("seed", "location").unwrap();
+    assert_eq!(composed.apply(max), Ok(9));
+    assert_eq!(composed.inverse(max), vec![9]);
+    let seeds = IntervalSet::from(value_range(max - 1, 2).unwrap());
+    assert_eq!(composed.min_over(&seeds), Ok(Some(8)));
+}
+
+#[test]
+fn reports_overflow_and_missing_seeds() {
+    assert!(value_range(u64::MAX, 1).is_ok());
+    assert!(value_range(u64::MAX, 2).is_err());
+
+    let almanac = example();
+    assert!(get_min_location(&[(u64::MAX, 2)], &almanac).is_err());
+    assert!(get_min_location(&[], &almanac).is_err());
+    assert!("seeds: 1 2 3\n\nseed-to-soil map:\n0 0 1"
+        .parse::<Almanac>()
+        .unwrap()
+        .seed_ranges()
+        .is_err());
+}
+
+#[test]
+fn maps_built_directly_are_checked_too() {
+    // Bypasses the check in parsing that the map stays within a u64
+    let almanac = Almanac {
+        seeds: vec![5, 1],
+        category_maps: vec![CategoryMap {
+            source: "seed".to_owned(),
+            destination: "location".to_owned(),
+            maps: vec![Map {
+                dest_start: u64::MAX,
+                src_start: 0,
+                length: 10,
+            }],
+        }],
+    };
+    assert!(almanac.map_value(5, "seed", "location").is_err());
+
+    let composed = almanac.compose("seed", "location").unwrap();
+    assert!(composed.apply(5).is_err());
+    assert_eq!(composed.apply(10), Ok(10));
+    let seeds = IntervalSet::from(value_range(5, 1).unwrap());
+    assert!(composed.min_over(&seeds).is_err());
+    assert!(get_min_location(&[(5, 1)], &almanac).is_err());
+}
+
+#[test]
+fn finds_locations_above_the_old_cap() {
+    // Every location is well over 10^9, which a fixed starting minimum would have hidden
+    let almanac: Almanac = "seeds: 1 2\n\nseed-to-location map:\n50000000000 0 10"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        get_min_location(&almanac.seed_ranges().unwrap(), &almanac),
+        Ok(50000000001)
+    );
+}
+
+proptest! {
+    #[test]
+    fn composed_map_matches_mapping_each_category(almanac in almanac(), values in prop::collection::vec(0..250u64, 1..10)) {
+        let composed = almanac.compose("seed", "location").unwrap();
+        for value in values {
+            prop_assert_eq!(composed.apply(value), almanac.map_value(value, "seed", "location"));
+            prop_assert!(composed.inverse(composed.apply(value).unwrap()).contains(&value));
+        }
+
+        // Breakpoints are increasing, and the offset really changes at each one
+        for (x, y) in composed.breakpoints().iter().zip(composed.breakpoints().iter().skip(1)) {
+            prop_assert!(x < y);
+        }
+        for ((_, _, a), (_, _, b)) in composed.segments().zip(composed.segments().skip(1)) {
+            prop_assert_ne!(a, b);
+        }
+    }
+
+    #[test]
+    fn inverse_finds_every_seed(almanac in almanac(), location in 0..250u64) {
+        let composed = almanac.compose("seed", "location").unwrap();
+        let expected: Vec<u64> = (0..400)
+            .filter(|&seed| almanac.map_value(seed, "seed", "location") == Ok(location))
+            .collect();
+        prop_assert_eq!(composed.inverse(location), expected);
+    }
+
+    #[test]
+    fn lowest_location_matches_range_splitting(almanac in almanac(), seeds in prop::collection::vec((0..200u64, 1..50u64), 1..4)) {
+        let composed = almanac.compose("seed", "location").unwrap();
+        let seed_ranges: IntervalSet<i128> = seeds.iter().map(|&(x, len)| value_range(x, len).unwrap()).collect();
+        let split = almanac.map_ranges(&seed_ranges, "seed", "location").unwrap();
+
+        prop_assert_eq!(composed.min_over(&seed_ranges), Ok(split.min().map(|x| x as u64)));
+        prop_assert_eq!(composed.min_over(&seed_ranges).unwrap(), get_min_location(&seeds, &almanac).ok());
+    }
+
+    #[test]
+    fn ranges_map_like_their_values(start in 0..120u64, len in 1..20u64) {
+        let almanac = example();
+        let seeds = IntervalSet::from(value_range(start, len).unwrap());
+        let mapped = almanac.map_ranges(&seeds, "seed", "humidity").unwrap();
+
+        let mut expected: Vec<u64> = (start..start + len)
+            .map(|seed| almanac.map_value(seed, "seed", "humidity").unwrap())
+            .collect();
+        expected.sort();
+        let values: Vec<u64> = mapped.intervals().iter().flat_map(|x| x.start as u64..x.end as u64).collect();
+        prop_assert_eq!(values, expected);
+    }
+}
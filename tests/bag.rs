@@ -0,0 +1,118 @@
+/*
+Tests for day 2's bag queries, checked against comparing each colour of each set directly.
+*/
+use proptest::prelude::*;
+
+use advent_of_code::solutions::y2023::day2::{
+    feasible_games, smallest_bag, Bag, BallCount, Game, GameSet,
+};
+
+const COLOURS: [&str; 4] = ["red", "green", "blue", "teal"];
+
+fn game_set() -> impl Strategy<Value = GameSet> {
+    let ball_count = (0..COLOURS.len(), 1..20u32).prop_map(|(i, count)| BallCount {
+        colour: COLOURS[i].to_owned(),
+        count,
+    });
+    prop::collection::vec(ball_count, 1..4).prop_map(|ball_counts| GameSet { ball_counts })
+}
+
+fn games() -> impl Strategy<Value = Vec<Game>> {
+    prop::collection::vec(prop::collection::vec(game_set(), 1..4), 0..6).prop_map(|sets| {
+        sets.into_iter()
+            .enumerate()
+            .map(|(i, game_sets)| Game {
+                game_id: i as u32 + 1,
+                game_sets,
+            })
+            .collect()
+    })
+}
+
+fn bag() -> impl Strategy<Value = Bag> {
+    prop::collection::vec(0..25u32, COLOURS.len())
+        .prop_map(|counts| COLOURS.iter().copied().zip(counts).collect())
+}
+
+#[test]
+fn answers_the_example_queries() {
+    let games: Vec<Game> = [
+        "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
+        "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
+    ]
+    .iter()
+    .map(|line| line.parse().unwrap())
+    .collect();
+    let bag: Bag = "12 red, 13 green, 14 blue".parse().unwrap();
+
+    assert_eq!(games[0].minimal_bag().to_string(), "6 blue, 2 green, 4 red");
+    assert_eq!(games[0].minimal_bag().power(), Some(48));
+    assert_eq!(feasible_games(&games, &bag), vec![&games[0]]);
+
+    let violations = games[1].violations(&bag);
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].set_index, 0);
+    assert_eq!(violations[0].excess.to_string(), "8 red");
+
+    assert_eq!(smallest_bag(&games).to_string(), "6 blue, 13 green, 20 red");
+}
+
+#[test]
+fn colours_missing_from_the_bag_count_as_none() {
+    let game: Game = "Game 1: 1 purple".parse().unwrap();
+    let bag: Bag = "12 red, 13 green, 14 blue".parse().unwrap();
+    assert!(!game.is_feasible(&bag));
+    assert_eq!(game.violations(&bag)[0].excess.to_string(), "1 purple");
+}
+
+proptest! {
+    #[test]
+    fn feasibility_matches_direct_comparison(games in games(), bag in bag()) {
+        let fits = |game: &Game| {
+            game.game_sets.iter().all(|set| {
+                set.ball_counts.iter().all(|ball| {
+                    let shown: u64 = set.ball_counts.iter().filter(|other| other.colour == ball.colour).map(|other| other.count as u64).sum();
+                    shown <= bag.get(&ball.colour)
+                })
+            })
+        };
+        let expected: Vec<&Game> = games.iter().filter(|game| fits(game)).collect();
+
+        prop_assert_eq!(feasible_games(&games, &bag), expected);
+        for game in games.iter() {
+            prop_assert_eq!(game.violations(&bag).is_empty(), fits(game));
+        }
+    }
+
+    #[test]
+    fn smallest_bag_covers_every_game_exactly(games in games()) {
+        let smallest = smallest_bag(&games);
+        prop_assert_eq!(feasible_games(&games, &smallest).len(), games.len());
+
+        // Taking away any cube leaves some game infeasible
+        for (colour, count) in smallest.colours() {
+            let mut smaller = smallest.clone();
+            smaller.set(colour, count - 1);
+            prop_assert!(feasible_games(&games, &smaller).len() < games.len());
+        }
+    }
+
+    #[test]
+    fn bags_round_trip(bag in bag()) {
+        prop_assert_eq!(bag.to_string().parse::<Bag>().unwrap(), bag);
+    }
+}
+
+#[test]
+fn repeated_colours_add_up_without_overflowing() {
+    let game: Game = "Game 1: 4294967295 red, 1 red; 2 blue".parse().unwrap();
+    let bag = game.minimal_bag();
+    assert_eq!(bag.get("red"), 4294967296);
+    assert_eq!(bag.to_string().parse::<Bag>(), Ok(bag.clone()));
+    assert_eq!(bag.power(), Some(8589934592));
+
+    let huge: Bag = "18446744073709551615 red, 2 blue".parse().unwrap();
+    assert_eq!(huge.power(), None);
+    assert!("18446744073709551615 red, 1 red".parse::<Bag>().is_err());
+    assert!("1 red, 2 gr33n".parse::<Bag>().is_err());
+}
@@ -0,0 +1,122 @@
+/*
+Tests for day 1's digit scanner, checked against the original approach of rewriting each
+spelled out digit as e.g. "one1one" and then taking the first and last numerals.
+*/
+use proptest::prelude::*;
+
+use advent_of_code::solutions::y2023::day1::{
+    explain_calibrations, get_calibration_value, Vocabulary,
+};
+
+const ENGLISH: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+fn value_by_rewriting(line: &str) -> Option<u32> {
+    let mut rewritten = line.to_owned();
+    for (i, word) in ENGLISH.iter().enumerate() {
+        rewritten = rewritten.replace(word, &format!("{word}{}{word}", i + 1));
+    }
+    let digits: Vec<u32> = rewritten.chars().filter_map(|c| c.to_digit(10)).collect();
+    Some(10 * digits.first()? + digits.last()?)
+}
+
+fn vocabulary(s: &str) -> Vocabulary {
+    s.parse().unwrap()
+}
+
+#[test]
+fn reads_overlapping_words() {
+    let english = vocabulary("numerals,english");
+    assert_eq!(get_calibration_value("eightwo", &english), Some(82));
+    assert_eq!(get_calibration_value("xtwone3four", &english), Some(24));
+    assert_eq!(get_calibration_value("seven", &english), Some(77));
+    assert_eq!(get_calibration_value("", &english), None);
+    assert_eq!(get_calibration_value("abc", &english), None);
+}
+
+#[test]
+fn vocabulary_can_be_configured() {
+    assert_eq!(get_calibration_value("zero3", &vocabulary("english")), None);
+    assert_eq!(
+        get_calibration_value("zero3", &vocabulary("numerals,zero=0")),
+        Some(3)
+    );
+    assert_eq!(
+        get_calibration_value("fünfundzwei", &vocabulary("german")),
+        Some(52)
+    );
+    assert_eq!(
+        get_calibration_value("i x v", &vocabulary("i=1, v=5, x=0")),
+        Some(15)
+    );
+    // Later entries override earlier ones
+    assert_eq!(
+        get_calibration_value("one", &vocabulary("english,one=7")),
+        Some(77)
+    );
+}
+
+#[test]
+fn numerals_keep_zero_but_words_for_it_are_left_out() {
+    assert_eq!(
+        get_calibration_value("0a5", &vocabulary("numerals")),
+        Some(5)
+    );
+    assert_eq!(
+        get_calibration_value("zero05", &vocabulary("numerals,english")),
+        Some(5)
+    );
+    for language in ["english", "german", "french", "spanish"] {
+        assert_eq!(
+            get_calibration_value("zero null zéro cero", &vocabulary(language)),
+            None
+        );
+    }
+}
+
+#[test]
+fn rejects_invalid_vocabularies() {
+    assert!("klingon".parse::<Vocabulary>().is_err());
+    assert!("ten=10".parse::<Vocabulary>().is_err());
+    assert!("=1".parse::<Vocabulary>().is_err());
+}
+
+#[test]
+fn explains_each_line() {
+    let calibrations = explain_calibrations("eightwo\n\nab3", &vocabulary("numerals,english"));
+    assert_eq!(calibrations.len(), 3);
+
+    let first = calibrations[0].first.as_ref().unwrap();
+    let last = calibrations[0].last.as_ref().unwrap();
+    assert_eq!(
+        (first.token.as_str(), first.position, first.digit),
+        ("eight", 0, 8)
+    );
+    assert_eq!(
+        (last.token.as_str(), last.position, last.digit),
+        ("two", 4, 2)
+    );
+    assert_eq!(calibrations[0].value, Some(82));
+
+    assert_eq!(calibrations[1].line, 2);
+    assert_eq!(calibrations[1].value, None);
+    assert_eq!(calibrations[2].value, Some(33));
+}
+
+proptest! {
+    #[test]
+    fn scanning_matches_rewriting(line in "(one|two|three|four|five|six|seven|eight|nine|[1-9]|[a-z]){0,8}") {
+        let english = vocabulary("numerals,english");
+        prop_assert_eq!(get_calibration_value(&line, &english), value_by_rewriting(&line));
+    }
+
+    #[test]
+    fn explanation_agrees_with_the_scanner(lines in prop::collection::vec("(one|two|eight|[1-9]|[a-z]){0,6}", 0..5)) {
+        let english = vocabulary("numerals,english");
+        let calibrations = explain_calibrations(&lines.join("\n"), &english);
+        let values: Vec<Option<u32>> = calibrations.iter().map(|c| c.value).collect();
+        let expected: Vec<Option<u32>> = lines.join("\n").lines().map(|line| get_calibration_value(line, &english)).collect();
+        prop_assert_eq!(values, expected);
+    }
+}
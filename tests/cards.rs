@@ -0,0 +1,85 @@
+/*
+Tests for day 4's copy cascade, checked against handing out copies one card at a time.
+*/
+use num::BigUint;
+use proptest::prelude::*;
+
+use advent_of_code::solutions::y2023::day4::{cascade, parse_cards, validate_ids, Card};
+
+fn card(card_id: u32, matches: u32) -> Card {
+    Card {
+        card_id,
+        winning_numbers: (0..matches).collect(),
+        my_numbers: (0..matches).collect(),
+    }
+}
+
+// Processes every single copy of every card, so only works for small piles
+fn copies_one_at_a_time(cards: &[Card]) -> Vec<u64> {
+    let mut copies = vec![0; cards.len()];
+    let mut pile: Vec<usize> = (0..cards.len()).collect();
+    while let Some(idx) = pile.pop() {
+        copies[idx] += 1;
+        let n_won = cards[idx].matches().min(cards.len() - idx - 1);
+        pile.extend(idx + 1..idx + 1 + n_won);
+    }
+    copies
+}
+
+#[test]
+fn traces_the_example_cascade() {
+    let cards = parse_cards(
+        "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11",
+    )
+    .unwrap();
+    let trace = cascade::<u64>(&cards).unwrap();
+
+    let copies: Vec<u64> = trace.iter().map(|card| card.copies).collect();
+    assert_eq!(copies, vec![1, 2, 4, 8, 14, 1]);
+    let won: Vec<u64> = trace.iter().map(|card| card.won).collect();
+    assert_eq!(won, vec![4, 4, 8, 8, 0, 0]);
+}
+
+#[test]
+fn rejects_missing_and_out_of_order_ids() {
+    let ids = |ids: &[u32]| ids.iter().map(|&id| card(id, 0)).collect::<Vec<_>>();
+    assert!(validate_ids(&ids(&[1, 2, 3])).is_ok());
+    assert_eq!(
+        validate_ids(&ids(&[1, 3])),
+        Err("Card 2 is missing, found card 3 after card 1".to_owned())
+    );
+    assert_eq!(
+        validate_ids(&ids(&[1, 2, 2])),
+        Err("Card 2 is out of order, expected card 3 after card 2".to_owned())
+    );
+    assert_eq!(
+        validate_ids(&ids(&[0, 1])),
+        Err("Card 0 is out of order, expected card 1 at the start".to_owned())
+    );
+    assert!(cascade::<u64>(&ids(&[2])).is_err());
+}
+
+#[test]
+fn counts_copies_past_u64() {
+    // Each card wins a copy of every later card, doubling the copies each time
+    let cards: Vec<Card> = (1..=70).map(|id| card(id, 70 - id)).collect();
+    let trace = cascade::<BigUint>(&cards).unwrap();
+    assert_eq!(trace[69].copies, BigUint::from(2u32).pow(69));
+
+    let total: BigUint = trace.iter().map(|card| &card.copies).sum();
+    assert_eq!(total, BigUint::from(2u32).pow(70) - 1u32);
+}
+
+proptest! {
+    #[test]
+    fn cascade_matches_handing_out_copies(matches in prop::collection::vec(0..5u32, 0..12)) {
+        let cards: Vec<Card> = (1..).zip(matches).map(|(id, m)| card(id, m)).collect();
+        let copies: Vec<u64> = cascade::<u64>(&cards).unwrap().iter().map(|card| card.copies).collect();
+        prop_assert_eq!(copies, copies_one_at_a_time(&cards));
+    }
+}
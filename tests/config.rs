@@ -0,0 +1,120 @@
+/*
+Tests for loading the runner configuration, and for which of the global, per-day and
+command line settings wins when more than one is given.
+*/
+use std::path::PathBuf;
+use std::time::Duration;
+
+use advent_of_code::config::{Config, OutputFormat};
+use advent_of_code::helpers::Params;
+use advent_of_code::solutions::y2023::day8;
+
+const CONFIG: &str = r#"
+year = 2022
+data_dir = "inputs"
+session_file = "token.txt"
+output = "json"
+explain = true
+timeout = 60
+
+[timeouts.y2023]
+day8 = 120
+
+[params.y2023.day11]
+expansion = 1000000
+
+[params.y2023.day8]
+start = "AAA"
+target = "ZZZ"
+"#;
+
+#[test]
+fn parses_every_setting() {
+    let config = Config::parse(CONFIG).unwrap();
+    assert_eq!(config.year, 2022);
+    assert_eq!(config.data_dir, PathBuf::from("inputs"));
+    assert_eq!(config.session_file, PathBuf::from("token.txt"));
+    assert_eq!(config.output, OutputFormat::Json);
+    assert!(config.explain);
+    assert_eq!(config.timeout, Some(60.));
+}
+
+#[test]
+fn missing_settings_use_the_defaults() {
+    let config = Config::parse("").unwrap();
+    let default = Config::default();
+    assert_eq!(config.year, default.year);
+    assert_eq!(config.data_dir, default.data_dir);
+    assert_eq!(config.output, OutputFormat::Text);
+    assert!(!config.explain);
+    assert_eq!(config.timeout_for(2023, 8), None);
+    // Resolving against no declared params only succeeds if none were set
+    assert!(Params::resolve(&[], &config.params_for(2023, 11)).is_ok());
+}
+
+#[test]
+fn rejects_unknown_settings() {
+    let error = Config::parse("colour = \"blue\"").unwrap_err();
+    assert!(error.contains("unknown field `colour`"), "{error}");
+
+    assert!(Config::parse("output = \"yaml\"").is_err());
+    assert!(Config::parse("year = \"last\"").is_err());
+}
+
+#[test]
+fn rejects_timeouts_that_are_not_positive() {
+    assert!(Config::parse("timeout = 0").is_err());
+    assert!(Config::parse("timeout = -1.5").is_err());
+    assert!(Config::parse("[timeouts.y2023]\nday8 = 0").is_err());
+    assert!(Config::parse("timeout = inf").is_err());
+    assert!(Config::parse("timeout = nan").is_err());
+    assert!(Config::parse("timeout = 1e30").is_err());
+    assert!(Config::parse("[timeouts.y2023]\nday8 = inf").is_err());
+}
+
+#[test]
+fn per_day_timeouts_override_the_global_one() {
+    let mut config = Config::parse(CONFIG).unwrap();
+    assert_eq!(config.timeout_for(2023, 8), Some(Duration::from_secs(120)));
+    assert_eq!(config.timeout_for(2023, 1), Some(Duration::from_secs(60)));
+    assert_eq!(config.timeout_for(2022, 8), Some(Duration::from_secs(60)));
+
+    // --timeout replaces every timeout from the file
+    config.set_timeout(0.5);
+    assert_eq!(
+        config.timeout_for(2023, 8),
+        Some(Duration::from_millis(500))
+    );
+    assert_eq!(
+        config.timeout_for(2023, 1),
+        Some(Duration::from_millis(500))
+    );
+}
+
+#[test]
+fn command_line_params_override_the_file() {
+    let mut config = Config::parse(CONFIG).unwrap();
+    assert_eq!(config.params_for(2023, 11).get::<u64>("expansion"), 1000000);
+
+    config.set_param(2023, 8, "target", "XXZ");
+    config.set_param(2023, 11, "expansion", "10");
+    let params = config.params_for(2023, 8);
+    assert_eq!(params.get::<String>("start"), "AAA");
+    assert_eq!(params.get::<String>("target"), "XXZ");
+    assert_eq!(config.params_for(2023, 11).get::<u64>("expansion"), 10);
+
+    // Params are kept separately for each year and day
+    config.set_param(2022, 11, "expansion", "2");
+    assert_eq!(config.params_for(2023, 11).get::<u64>("expansion"), 10);
+    assert_eq!(config.params_for(2022, 11).get::<u64>("expansion"), 2);
+}
+
+#[test]
+fn resolved_params_fill_in_defaults() {
+    let mut config = Config::parse(CONFIG).unwrap();
+    config.set_param(2023, 8, "ghost_start", "B");
+    let params = config.resolved_params(2023, 8, &day8::PARAMS);
+    assert_eq!(params.get::<String>("target"), "ZZZ");
+    assert_eq!(params.get::<String>("ghost_start"), "B");
+    assert_eq!(params.get::<String>("ghost_target"), "Z");
+}
@@ -0,0 +1,49 @@
+use proptest::prelude::*;
+
+use advent_of_code::utils::diff::{checked_diff_n, checked_diff_with, diff_with};
+use advent_of_code::utils::{checked_diff, diff, diff_iter, diff_n, DiffMode};
+
+#[test]
+fn differences_go_forward_by_default() {
+    assert_eq!(diff(&[1, 3, 6, 10]), vec![2, 3, 4]);
+    assert_eq!(
+        diff_with(&[1, 3, 6, 10], DiffMode::Backward),
+        vec![-2, -3, -4]
+    );
+    assert_eq!(
+        diff_iter([1, 3, 6, 10]).collect::<Vec<i32>>(),
+        vec![2, 3, 4]
+    );
+    assert_eq!(diff_n(&[1, 3, 6, 10], 2), vec![1, 1]);
+    assert!(diff::<i64>(&[]).is_empty());
+}
+
+#[test]
+fn checked_differences_catch_overflow() {
+    assert_eq!(checked_diff(&[1u32, 3, 6]), Some(vec![2, 3]));
+    assert_eq!(checked_diff(&[3u32, 1]), None);
+    assert_eq!(
+        checked_diff_with(&[3u32, 1], DiffMode::Backward),
+        Some(vec![2])
+    );
+    assert_eq!(checked_diff(&[i64::MIN, i64::MAX]), None);
+    assert_eq!(checked_diff_n(&[0u8, 2, 3], 2), None);
+}
+
+proptest! {
+    #[test]
+    fn diff_n_is_repeated_diff(x in prop::collection::vec(-1000..1000i64, 0..10), n in 0..12usize) {
+        let mut expected = x.clone();
+        for _ in 0..n {
+            expected = diff(&expected);
+        }
+        prop_assert_eq!(diff_n(&x, n), expected.clone());
+        prop_assert_eq!(checked_diff_n(&x, n), Some(expected));
+    }
+
+    #[test]
+    fn slice_and_iterator_forms_agree(x in prop::collection::vec(-1000..1000i64, 0..10)) {
+        prop_assert_eq!(diff_iter(x.iter().copied()).collect::<Vec<_>>(), diff(&x));
+        prop_assert_eq!(checked_diff(&x), Some(diff(&x)));
+    }
+}
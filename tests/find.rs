@@ -0,0 +1,66 @@
+use proptest::prelude::*;
+
+use advent_of_code::utils::{find_first, find_last, Match};
+
+const WORDS: [&str; 4] = ["one", "eight", "two", "ei"];
+
+// Every (position, pattern) where a pattern occurs, longest first at each position
+fn all_matches(line: &str, patterns: &[&str]) -> Vec<(usize, usize)> {
+    let mut matches = vec![];
+    for (position, _) in line.char_indices() {
+        let mut here: Vec<usize> = (0..patterns.len())
+            .filter(|&i| !patterns[i].is_empty() && line[position..].starts_with(patterns[i]))
+            .collect();
+        here.sort_by_key(|&i| std::cmp::Reverse(patterns[i].len()));
+        matches.extend(here.into_iter().map(|i| (position, i)));
+    }
+    matches
+}
+
+#[test]
+fn finds_overlapping_tokens() {
+    assert_eq!(
+        find_first("xeightwo", &WORDS),
+        Some(Match {
+            pattern: 1,
+            token: "eight",
+            position: 1
+        })
+    );
+    assert_eq!(
+        find_last("xeightwo", &WORDS),
+        Some(Match {
+            pattern: 2,
+            token: "two",
+            position: 5
+        })
+    );
+}
+
+#[test]
+fn empty_lines_have_no_match() {
+    assert_eq!(find_first("", &WORDS), None);
+    assert_eq!(find_last("", &WORDS), None);
+    assert_eq!(find_first("abc", &[""]), None);
+}
+
+#[test]
+fn positions_are_bytes_on_char_boundaries() {
+    let patterns = ["é", "ü2"];
+    assert_eq!(find_first("aéü2", &patterns).map(|m| m.position), Some(1));
+    assert_eq!(find_last("aéü2", &patterns).map(|m| m.position), Some(3));
+}
+
+proptest! {
+    #[test]
+    fn first_and_last_match_brute_force(line in "[a-z0-9éü]{0,20}", patterns in prop::collection::vec("[a-zü]{0,3}", 0..5)) {
+        let patterns: Vec<&str> = patterns.iter().map(String::as_str).collect();
+        let matches = all_matches(&line, &patterns);
+        // The longest match at the last position anything matches
+        let last_position = matches.last().map(|&(position, _)| position);
+        let expected_last = matches.iter().find(|&&(position, _)| Some(position) == last_position);
+
+                prop_assert_eq!(find_first(&line, &patterns).map(|m| (m.position, m.pattern)), matches.first().copied());
+        prop_assert_eq!(find_last(&line, &patterns).map(|m| (m.position, m.pattern)), expected_last.copied());
+    }
+}
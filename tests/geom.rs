@@ -0,0 +1,101 @@
+/*
+Property tests for points, vectors and directions, checked against signed arithmetic so
+that moves off the top or left edge give `None` instead of wrapping around.
+*/
+use proptest::prelude::*;
+
+use advent_of_code::utils::{Direction, Point, Vector};
+
+fn point() -> impl Strategy<Value = Point> {
+    (0..20usize, 0..20usize).prop_map(|(row, col)| Point::new(row, col))
+}
+
+fn vector() -> impl Strategy<Value = Vector> {
+    (-25..25isize, -25..25isize).prop_map(|(row, col)| Vector::new(row, col))
+}
+
+fn direction() -> impl Strategy<Value = Direction> {
+    prop::sample::select(Direction::ALL.to_vec())
+}
+
+proptest! {
+    #[test]
+    fn checked_add_matches_signed_arithmetic(point in point(), vector in vector()) {
+        let row = point.row as isize + vector.row;
+        let col = point.col as isize + vector.col;
+        let expected = (row >= 0 && col >= 0).then(|| Point::new(row as usize, col as usize));
+        prop_assert_eq!(point.checked_add(vector), expected);
+        if let Some(moved) = expected {
+            prop_assert_eq!(moved - point, vector);
+        }
+    }
+
+    #[test]
+    fn distances_match_the_vector_between_points(a in point(), b in point()) {
+        let (dr, dc) = (a.row.abs_diff(b.row), a.col.abs_diff(b.col));
+        prop_assert_eq!(a.manhattan(b), dr + dc);
+        prop_assert_eq!(a.chebyshev(b), dr.max(dc));
+        prop_assert_eq!(a.manhattan(b), b.manhattan(a));
+        prop_assert_eq!((a - b).manhattan(), a.manhattan(b));
+        prop_assert_eq!((a - b).chebyshev(), a.chebyshev(b));
+        prop_assert!(a.chebyshev(b) <= a.manhattan(b));
+    }
+
+    #[test]
+    fn turns_compose(direction in direction()) {
+        prop_assert_eq!(direction.turn_right().turn_left(), direction);
+        prop_assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+        prop_assert_eq!(direction.turn_left().turn_left(), direction.opposite());
+        prop_assert_eq!(direction.opposite().opposite(), direction);
+        prop_assert_eq!(direction.opposite().vector(), -direction.vector());
+        prop_assert_eq!(direction.vector().manhattan(), 1);
+    }
+
+    #[test]
+    fn stepping_there_and_back_returns_to_the_start(point in point(), direction in direction()) {
+        if let Some(next) = point.step(direction) {
+            prop_assert_eq!(next.step(direction.opposite()), Some(point));
+            prop_assert_eq!(next.manhattan(point), 1);
+        }
+    }
+}
+
+#[test]
+fn steps_off_the_top_and_left_edges_are_none() {
+    let origin = Point::new(0, 0);
+    assert_eq!(origin.step(Direction::Up), None);
+    assert_eq!(origin.step(Direction::Left), None);
+    assert_eq!(origin.step(Direction::Down), Some(Point::new(1, 0)));
+    assert_eq!(origin.step(Direction::Right), Some(Point::new(0, 1)));
+
+    assert_eq!(Point::new(0, 5).step(Direction::Up), None);
+    assert_eq!(Point::new(5, 0).step(Direction::Left), None);
+    assert_eq!(
+        Point::new(3, 0).checked_add(Vector::new(-3, 0)),
+        Some(Point::new(0, 0))
+    );
+    assert_eq!(Point::new(3, 0).checked_add(Vector::new(-4, 0)), None);
+}
+
+#[test]
+fn neighbours_at_the_edges() {
+    assert_eq!(Point::new(0, 0).neighbours4().count(), 2);
+    assert_eq!(Point::new(0, 0).neighbours8().count(), 3);
+    assert_eq!(Point::new(0, 3).neighbours8().count(), 5);
+    assert_eq!(Point::new(3, 3).neighbours8().count(), 8);
+}
+
+#[test]
+fn turns_go_round_clockwise() {
+    let mut direction = Direction::Up;
+    for expected in [
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+        Direction::Up,
+    ] {
+        direction = direction.turn_right();
+        assert_eq!(direction, expected);
+    }
+    assert_eq!(Direction::Up.turn_left(), Direction::Left);
+}
@@ -0,0 +1,258 @@
+/*
+Property tests for the graph searches, checked against Floyd-Warshall shortest paths and
+transitive closure on small random graphs, and against stepping through every node for
+cycle finding.
+*/
+use hashbrown::HashMap;
+use proptest::prelude::*;
+
+use advent_of_code::utils::graph::{
+    astar, bfs, bfs_path, dfs, dijkstra, find_cycle, topological_sort, Cycle,
+};
+
+/// Small directed graph on nodes `0..n`, as a list of weighted edges.
+#[derive(Debug, Clone)]
+struct Edges {
+    n: usize,
+    edges: Vec<(usize, usize, u32)>,
+}
+
+impl Edges {
+    fn adjacency(&self) -> HashMap<usize, Vec<(usize, u32)>> {
+        let mut adjacency: HashMap<usize, Vec<(usize, u32)>> = HashMap::new();
+        for &(from, to, weight) in self.edges.iter() {
+            adjacency.entry(from).or_default().push((to, weight));
+        }
+        adjacency
+    }
+
+    fn unweighted(&self) -> HashMap<usize, Vec<usize>> {
+        let mut adjacency: HashMap<usize, Vec<usize>> = HashMap::new();
+        for &(from, to, _) in self.edges.iter() {
+            adjacency.entry(from).or_default().push(to);
+        }
+        adjacency
+    }
+
+    /// Cheapest cost between every pair of nodes, where paths have at least one edge.
+    fn floyd_warshall(&self, unit_weights: bool) -> Vec<Vec<Option<u32>>> {
+        let mut dist = vec![vec![None; self.n]; self.n];
+        for &(from, to, weight) in self.edges.iter() {
+            let weight = if unit_weights { 1 } else { weight };
+            dist[from][to] = Some(dist[from][to].map_or(weight, |d: u32| d.min(weight)));
+        }
+        for k in 0..self.n {
+            for i in 0..self.n {
+                for j in 0..self.n {
+                    if let (Some(a), Some(b)) = (dist[i][k], dist[k][j]) {
+                        dist[i][j] = Some(dist[i][j].map_or(a + b, |d| d.min(a + b)));
+                    }
+                }
+            }
+        }
+        dist
+    }
+
+    /// Shortest distances from a node, counting the node itself as 0.
+    fn distances_from(&self, start: usize, unit_weights: bool) -> Vec<Option<u32>> {
+        let mut dist = self.floyd_warshall(unit_weights)[start].clone();
+        dist[start] = Some(0);
+        dist
+    }
+
+    fn cheapest_edge(&self, from: usize, to: usize) -> Option<u32> {
+        self.edges
+            .iter()
+            .filter(|&&(f, t, _)| (f, t) == (from, to))
+            .map(|&(_, _, weight)| weight)
+            .min()
+    }
+}
+
+fn edges(max_weight: u32) -> impl Strategy<Value = Edges> {
+    (1..8usize).prop_flat_map(move |n| {
+        prop::collection::vec((0..n, 0..n, 0..=max_weight), 0..20)
+            .prop_map(move |edges| Edges { n, edges })
+    })
+}
+
+/// Random DAG, with every edge going from an earlier to a later node in a shuffled order.
+fn dag() -> impl Strategy<Value = Edges> {
+    edges(0)
+        .prop_flat_map(|graph| {
+            let order = Just((0..graph.n).collect::<Vec<_>>()).prop_shuffle();
+            (Just(graph), order)
+        })
+        .prop_map(|(graph, order)| {
+            let edges = graph
+                .edges
+                .iter()
+                .filter(|(from, to, _)| from != to)
+                .map(|&(from, to, weight)| {
+                    let (a, b) = (from.min(to), from.max(to));
+                    (order[a], order[b], weight)
+                })
+                .collect();
+            Edges { n: graph.n, edges }
+        })
+}
+
+/// Checks a path follows the graph's edges, returning its cheapest cost.
+fn path_cost(graph: &Edges, path: &[usize]) -> Option<u32> {
+    path.windows(2)
+        .map(|pair| graph.cheapest_edge(pair[0], pair[1]))
+        .sum()
+}
+
+proptest! {
+    #[test]
+    fn bfs_matches_floyd_warshall(graph in edges(0), start in 0..8usize) {
+        let start = start % graph.n;
+        let expected = graph.distances_from(start, true);
+        let distances = bfs(&graph.unweighted(), start);
+
+        for (node, &expected) in expected.iter().enumerate() {
+            prop_assert_eq!(distances.get(&node).map(|&d| d as u32), expected);
+        }
+    }
+
+    #[test]
+    fn bfs_path_is_a_shortest_path(graph in edges(0), start in 0..8usize, goal in 0..8usize) {
+        let (start, goal) = (start % graph.n, goal % graph.n);
+        let expected = graph.distances_from(start, true)[goal];
+        let adjacency = graph.unweighted();
+        let neighbours = |&node: &usize| adjacency.get(&node).cloned().unwrap_or_default();
+        let path = bfs_path(&neighbours, start, |&node| node == goal);
+
+        prop_assert_eq!(path.as_ref().map(|path| path.len() as u32 - 1), expected);
+        if let Some(path) = path {
+            prop_assert_eq!((path[0], path[path.len() - 1]), (start, goal));
+            prop_assert!(path_cost(&graph, &path).is_some());
+        }
+    }
+
+    #[test]
+    fn dfs_visits_each_reachable_node_once(graph in edges(0), start in 0..8usize) {
+        let start = start % graph.n;
+        let reachable = graph.distances_from(start, true);
+        let order = dfs(&graph.unweighted(), start);
+
+        prop_assert_eq!(order[0], start);
+        let mut visited = order.clone();
+        visited.sort();
+        let expected: Vec<usize> = (0..graph.n).filter(|&node| reachable[node].is_some()).collect();
+        prop_assert_eq!(visited, expected);
+
+        // Every node is found from one visited before it
+        for (idx, &node) in order.iter().enumerate().skip(1) {
+            prop_assert!(order[..idx].iter().any(|&prev| graph.cheapest_edge(prev, node).is_some()));
+        }
+    }
+
+    #[test]
+    fn dijkstra_matches_floyd_warshall(graph in edges(20), start in 0..8usize, goal in 0..8usize) {
+        let (start, goal) = (start % graph.n, goal % graph.n);
+        let expected = graph.distances_from(start, false)[goal];
+        let result = dijkstra(&graph.adjacency(), start, |&node| node == goal);
+
+        prop_assert_eq!(result.as_ref().map(|(cost, _)| *cost), expected);
+        if let Some((cost, path)) = result {
+            prop_assert_eq!((path[0], path[path.len() - 1]), (start, goal));
+            prop_assert_eq!(path_cost(&graph, &path), Some(cost));
+        }
+    }
+
+    /*
+    Scaling the true remaining cost by a different fraction at each node gives a heuristic
+    that never overestimates but isn't consistent, so nodes get queued again when a cheaper
+    path to them turns up and the older entries have to be skipped.
+    */
+    #[test]
+    fn astar_matches_floyd_warshall(
+        graph in edges(20),
+        start in 0..8usize,
+        goal in 0..8usize,
+        scales in prop::collection::vec(0..=4u32, 8),
+    ) {
+        let (start, goal) = (start % graph.n, goal % graph.n);
+        let all_pairs = graph.floyd_warshall(false);
+        let remaining = |node: usize| if node == goal { Some(0) } else { all_pairs[node][goal] };
+        let heuristic = |&node: &usize| remaining(node).map_or(0, |d| d * scales[node] / 4);
+
+        let adjacency = graph.adjacency();
+        let edges = |&node: &usize| adjacency.get(&node).cloned().unwrap_or_default();
+        let result = astar(&edges, start, |&node| node == goal, heuristic);
+
+        prop_assert_eq!(result.as_ref().map(|(cost, _)| *cost), remaining(start));
+        if let Some((cost, path)) = result {
+            prop_assert_eq!((path[0], path[path.len() - 1]), (start, goal));
+            prop_assert_eq!(path_cost(&graph, &path), Some(cost));
+        }
+    }
+
+    #[test]
+    fn topological_sort_orders_a_dag(graph in dag(), roots in prop::collection::vec(0..8usize, 1..3)) {
+        let roots: Vec<usize> = roots.iter().map(|root| root % graph.n).collect();
+        let order = topological_sort(&graph.unweighted(), roots.clone()).unwrap();
+
+        let all_pairs = graph.floyd_warshall(true);
+        let mut reachable: Vec<usize> = (0..graph.n)
+            .filter(|&node| roots.iter().any(|&root| root == node || all_pairs[root][node].is_some()))
+            .collect();
+        let mut sorted = order.clone();
+        sorted.sort();
+        reachable.sort();
+        prop_assert_eq!(sorted, reachable);
+
+        let position = |node: usize| order.iter().position(|&x| x == node);
+        for &(from, to, _) in graph.edges.iter() {
+            if let (Some(from), Some(to)) = (position(from), position(to)) {
+                prop_assert!(from < to);
+            }
+        }
+    }
+
+    #[test]
+    fn topological_sort_rejects_reachable_cycles(graph in edges(0), root in 0..8usize) {
+        let root = root % graph.n;
+        let all_pairs = graph.floyd_warshall(true);
+        let has_cycle = (0..graph.n).any(|node| {
+            (node == root || all_pairs[root][node].is_some()) && all_pairs[node][node].is_some()
+        });
+
+        let order = topological_sort(&graph.unweighted(), [root]);
+        prop_assert_eq!(order.is_none(), has_cycle);
+    }
+
+    #[test]
+    fn find_cycle_matches_stepping_through(next in prop::collection::vec(0..12usize, 1..12), start in 0..12usize) {
+        let next: Vec<usize> = next.iter().map(|&x| x % next.len()).collect();
+        let start = start % next.len();
+
+        // Step until a node repeats, remembering when each was first seen
+        let mut seen: HashMap<usize, usize> = HashMap::new();
+        let mut node = start;
+        let mut steps = 0;
+        while !seen.contains_key(&node) {
+            seen.insert(node, steps);
+            node = next[node];
+            steps += 1;
+        }
+        let tail = seen[&node];
+        let expected = Cycle { tail, period: steps - tail };
+
+        prop_assert_eq!(find_cycle(start, |&node| next[node]), expected);
+    }
+}
+
+#[test]
+fn searches_on_closures() {
+    // Reaching 10 from 1 by adding one or doubling
+    let graph = |&x: &u32| [x + 1, x * 2].into_iter().filter(|&y| y <= 20);
+    assert_eq!(bfs(&graph, 1)[&10], 4);
+    assert_eq!(
+        bfs_path(&graph, 1, |&x| x == 10),
+        Some(vec![1, 2, 4, 5, 10])
+    );
+    assert_eq!(bfs_path(&graph, 1, |&x| x == 0), None);
+}
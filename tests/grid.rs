@@ -0,0 +1,115 @@
+/*
+Property tests for the grid, checked against where each cell should end up after a
+transformation and against brute force over every cell for neighbours.
+*/
+use proptest::prelude::*;
+
+use advent_of_code::utils::Grid;
+
+fn grid() -> impl Strategy<Value = Grid<u8>> {
+    (1..6usize, 1..6usize)
+        .prop_flat_map(|(n_rows, n_cols)| {
+            prop::collection::vec(prop::collection::vec(any::<u8>(), n_cols), n_rows)
+        })
+        .prop_map(|rows| Grid::from_rows(rows).unwrap())
+}
+
+proptest! {
+    #[test]
+    fn rotating_four_times_is_the_identity(grid in grid()) {
+        let clockwise = (0..4).fold(grid.clone(), |grid, _| grid.rotate_clockwise());
+        prop_assert_eq!(&clockwise, &grid);
+        let anticlockwise = (0..4).fold(grid.clone(), |grid, _| grid.rotate_anticlockwise());
+        prop_assert_eq!(&anticlockwise, &grid);
+        prop_assert_eq!(&grid.rotate_clockwise().rotate_anticlockwise(), &grid);
+    }
+
+    #[test]
+    fn transposing_twice_is_the_identity(grid in grid()) {
+        prop_assert_eq!(&grid.transpose().transpose(), &grid);
+    }
+
+    #[test]
+    fn cells_move_to_the_right_place(grid in grid()) {
+        let (n_rows, n_cols) = (grid.n_rows(), grid.n_cols());
+        let transposed = grid.transpose();
+        let clockwise = grid.rotate_clockwise();
+        let anticlockwise = grid.rotate_anticlockwise();
+        prop_assert_eq!((transposed.n_rows(), transposed.n_cols()), (n_cols, n_rows));
+        prop_assert_eq!((clockwise.n_rows(), clockwise.n_cols()), (n_cols, n_rows));
+
+        for ((row, col), value) in grid.iter() {
+            prop_assert_eq!(&transposed[(col, row)], value);
+            prop_assert_eq!(&clockwise[(col, n_rows - 1 - row)], value);
+            prop_assert_eq!(&anticlockwise[(n_cols - 1 - col, row)], value);
+        }
+    }
+
+    #[test]
+    fn neighbours_match_brute_force(grid in grid()) {
+        for ((row, col), _) in grid.iter() {
+            let mut expected4 = vec![];
+            let mut expected8 = vec![];
+            for ((r, c), _) in grid.iter() {
+                let (dr, dc) = (r.abs_diff(row), c.abs_diff(col));
+                if (dr, dc) != (0, 0) && dr <= 1 && dc <= 1 {
+                    expected8.push((r, c));
+                    if dr + dc == 1 {
+                        expected4.push((r, c));
+                    }
+                }
+            }
+
+            let mut neighbours4: Vec<_> = grid.neighbours4(row, col).collect();
+            let mut neighbours8: Vec<_> = grid.neighbours8(row, col).collect();
+            neighbours4.sort();
+            neighbours8.sort();
+            prop_assert_eq!(neighbours4, expected4);
+            prop_assert_eq!(neighbours8, expected8);
+        }
+    }
+
+    #[test]
+    fn display_round_trips(grid in grid()) {
+        let chars = grid.map(|&value| char::from(b'a' + value % 26));
+        prop_assert_eq!(chars.to_string().parse::<Grid<char>>(), Ok(chars));
+    }
+}
+
+#[test]
+fn neighbour_counts_at_corners_edges_and_centre() {
+    let grid = Grid::new(3, 3, '.');
+    let counts = |row, col| {
+        (
+            grid.neighbours4(row, col).count(),
+            grid.neighbours8(row, col).count(),
+        )
+    };
+    for (row, col) in [(0, 0), (0, 2), (2, 0), (2, 2)] {
+        assert_eq!(counts(row, col), (2, 3));
+    }
+    for (row, col) in [(0, 1), (1, 0), (1, 2), (2, 1)] {
+        assert_eq!(counts(row, col), (3, 5));
+    }
+    assert_eq!(counts(1, 1), (4, 8));
+
+    let single = Grid::new(1, 1, '.');
+    assert_eq!(single.neighbours8(0, 0).count(), 0);
+}
+
+#[test]
+fn ragged_rows_are_rejected() {
+    let error = Grid::from_rows(vec![vec![1, 2], vec![3, 4], vec![5]]).unwrap_err();
+    assert_eq!(error, "Row 2 has 1 columns, expected 2 like the first row");
+    assert!("ab\ncd\n\nef".parse::<Grid<char>>().is_err());
+}
+
+#[test]
+fn blank_lines_around_the_block_are_ignored() {
+    let grid: Grid<char> = "\n  \nab\ncd\n\n \n".parse().unwrap();
+    assert_eq!((grid.n_rows(), grid.n_cols()), (2, 2));
+    assert_eq!(grid.to_string(), "ab\ncd");
+
+    let empty: Grid<char> = "\n\n".parse().unwrap();
+    assert_eq!((empty.n_rows(), empty.n_cols()), (0, 0));
+}
@@ -0,0 +1,59 @@
+/*
+Tests for day 7's hands with Jokers, checked against trying every card each Joker could be.
+*/
+use proptest::prelude::*;
+use std::cmp::Ordering;
+
+use advent_of_code::solutions::y2023::day7::{Hand, HandType, CARD_ORDER};
+
+fn cards() -> impl Strategy<Value = String> {
+    prop::collection::vec(prop::sample::select(CARD_ORDER.to_vec()), 5)
+        .prop_map(|cards| cards.into_iter().collect())
+}
+
+/// Best hand type from replacing every 'J' with the same card, which is always best for Jokers.
+fn best_type(cards: &str) -> HandType {
+    CARD_ORDER
+        .iter()
+        .map(|&card| Hand::new(&cards.replace('J', &card.to_string()), 0, false).hand_type)
+        .min()
+        .unwrap()
+}
+
+proptest! {
+    #[test]
+    fn jokers_make_the_best_hand(cards in cards()) {
+        let hand = Hand::new(&cards, 0, true);
+        prop_assert_eq!(hand.hand_type, best_type(&cards));
+        prop_assert!(hand.hand_type <= Hand::new(&cards, 0, false).hand_type);
+    }
+}
+
+#[test]
+fn jokers_rank_below_every_other_card() {
+    let [jokers, twos] = ["JKKK2", "2KKKK"].map(|cards| Hand::new(cards, 0, true));
+    assert_eq!(jokers.hand_type, HandType::FourKind);
+    assert_eq!(twos.hand_type, HandType::FourKind);
+    assert_eq!(Hand::compare(&jokers, &twos), Ordering::Greater);
+
+    // Without Jokers, a Jack ranks above a Ten as usual
+    let [jacks, tens] = ["JKKK2", "TKKK2"].map(|cards| Hand::new(cards, 0, false));
+    assert_eq!(Hand::compare(&jacks, &tens), Ordering::Less);
+}
+
+#[test]
+fn example_hands_with_jokers() {
+    let types = ["32T3K", "T55J5", "KK677", "KTJJT", "QQQJA"]
+        .map(|cards| Hand::new(cards, 0, true).hand_type)
+        .to_vec();
+    assert_eq!(
+        types,
+        vec![
+            HandType::OnePair,
+            HandType::FourKind,
+            HandType::TwoPair,
+            HandType::FourKind,
+            HandType::FourKind
+        ]
+    );
+}
@@ -0,0 +1,111 @@
+/*
+Property tests for the interval types, checked point by point against sets of values
+over a small range.
+*/
+use itertools::Itertools;
+use proptest::prelude::*;
+use std::collections::BTreeSet;
+
+use advent_of_code::utils::{Interval, IntervalSet, PiecewiseOffsetMap};
+
+const MAX: i64 = 60;
+
+fn interval() -> impl Strategy<Value = Interval<i64>> {
+    (0..MAX, 0..MAX).prop_map(|(start, end)| Interval::new(start, end))
+}
+
+fn interval_set() -> impl Strategy<Value = (IntervalSet<i64>, BTreeSet<i64>)> {
+    prop::collection::vec(interval(), 0..6).prop_map(|intervals| {
+        let points = intervals.iter().flat_map(|x| x.start..x.end).collect();
+        (intervals.into_iter().collect(), points)
+    })
+}
+
+// Pieces which don't overlap, made by cutting 0..MAX at sorted points
+fn offset_map() -> impl Strategy<Value = Vec<(Interval<i64>, i64)>> {
+    (
+        prop::collection::btree_set(0..MAX, 0..10),
+        prop::collection::vec((any::<bool>(), -MAX..MAX), 10),
+    )
+        .prop_map(|(cuts, offsets)| {
+            cuts.into_iter()
+                .tuple_windows()
+                .zip(offsets)
+                .filter(|(_, (keep, _))| *keep)
+                .map(|((start, end), (_, offset))| (Interval::new(start, end), offset))
+                .collect()
+        })
+}
+
+fn points(set: &IntervalSet<i64>) -> BTreeSet<i64> {
+    set.intervals()
+        .iter()
+        .flat_map(|x| x.start..x.end)
+        .collect()
+}
+
+fn is_coalesced(set: &IntervalSet<i64>) -> bool {
+    let intervals = set.intervals();
+    intervals.iter().all(|x| !x.is_empty())
+        && intervals
+            .iter()
+            .tuple_windows()
+            .all(|(x, y)| x.end < y.start)
+}
+
+proptest! {
+    #[test]
+    fn set_has_the_values_of_its_intervals((set, expected) in interval_set()) {
+        prop_assert!(is_coalesced(&set));
+        prop_assert_eq!(points(&set), expected.clone());
+        for value in -1..=MAX {
+            prop_assert_eq!(set.contains(value), expected.contains(&value));
+        }
+        prop_assert_eq!(set.min(), expected.first().copied());
+    }
+
+    #[test]
+    fn union_matches_points((x, x_points) in interval_set(), (y, y_points) in interval_set()) {
+        let union = x.union(&y);
+        prop_assert!(is_coalesced(&union));
+        prop_assert_eq!(points(&union), &x_points | &y_points);
+    }
+
+    #[test]
+    fn intersection_matches_points((x, x_points) in interval_set(), (y, y_points) in interval_set()) {
+        let intersection = x.intersection(&y);
+        prop_assert!(is_coalesced(&intersection));
+        prop_assert_eq!(points(&intersection), &x_points & &y_points);
+    }
+
+    #[test]
+    fn difference_matches_points((x, x_points) in interval_set(), (y, y_points) in interval_set()) {
+        let difference = x.difference(&y);
+        prop_assert!(is_coalesced(&difference));
+        prop_assert_eq!(points(&difference), &x_points - &y_points);
+    }
+
+    #[test]
+    fn map_set_matches_mapping_each_value(pieces in offset_map(), (set, set_points) in interval_set()) {
+        let map = PiecewiseOffsetMap::new(pieces.clone()).unwrap();
+        let map_point = |value: i64| {
+            pieces
+                .iter()
+                .find(|(interval, _)| interval.contains(value))
+                .map_or(value, |(_, offset)| value + offset)
+        };
+
+        for value in 0..MAX {
+            prop_assert_eq!(map.map_value(value), map_point(value));
+        }
+        let mapped = map.map_set(&set);
+        prop_assert!(is_coalesced(&mapped));
+        prop_assert_eq!(points(&mapped), set_points.into_iter().map(map_point).collect());
+    }
+}
+
+#[test]
+fn overlapping_pieces_are_an_error() {
+    let pieces = [(Interval::new(0, 10), 5), (Interval::new(9, 12), -5)];
+    assert!(PiecewiseOffsetMap::new(pieces).is_err());
+}
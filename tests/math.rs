@@ -0,0 +1,150 @@
+/*
+Property tests for the math utilities, checked against brute force over small inputs and
+against the defining inequalities for large ones.
+*/
+use num::{BigInt, BigRational};
+use proptest::prelude::*;
+
+use advent_of_code::utils::math::{
+    crt, isqrt_u128, isqrt_u64, lagrange_eval, quadratic_negative_range, Polynomial,
+};
+
+fn ratio(x: i64) -> BigRational {
+    BigRational::from_integer(BigInt::from(x))
+}
+
+proptest! {
+    #[test]
+    fn crt_finds_the_smallest_solution(congruences in prop::collection::vec((0..60u64, 1..30u64), 1..4)) {
+        let lcm = congruences.iter().fold(1, |acc, &(_, m)| num::integer::lcm(acc, m));
+        let solves = |x: u64| congruences.iter().all(|&(a, m)| x % m == a % m);
+        let expected = (0..lcm).find(|&x| solves(x));
+
+        prop_assert_eq!(crt(congruences.iter().copied()), expected.map(|x| (x, lcm)));
+    }
+
+    #[test]
+    fn crt_handles_large_moduli(x in any::<u64>(), m1 in 1..u32::MAX as u64, m2 in 1..u32::MAX as u64) {
+        let (residue, modulus) = crt([(x % m1, m1), (x % m2, m2)]).unwrap();
+        prop_assert_eq!(modulus, num::integer::lcm(m1, m2));
+        prop_assert_eq!(residue, x % modulus);
+    }
+
+    #[test]
+    fn isqrt_u64_is_the_floor_of_the_root(n in any::<u64>()) {
+        let root = isqrt_u64(n);
+        prop_assert!(root * root <= n);
+        prop_assert!((root + 1).checked_mul(root + 1).is_none_or(|square| square > n));
+    }
+
+    #[test]
+    fn isqrt_u128_is_the_floor_of_the_root(n in any::<u128>()) {
+        let root = isqrt_u128(n);
+        prop_assert!(root * root <= n);
+        prop_assert!((root + 1).checked_mul(root + 1).is_none_or(|square| square > n));
+    }
+
+    #[test]
+    fn isqrt_is_exact_on_squares_and_their_neighbours(root in any::<u64>()) {
+        let square = root as u128 * root as u128;
+        prop_assert_eq!(isqrt_u128(square), root as u128);
+        prop_assert_eq!(isqrt_u128(square - (root > 0) as u128), root.saturating_sub(1) as u128);
+    }
+
+    #[test]
+    fn quadratic_negative_range_matches_brute_force(a in 1..20i64, b in -500..500i64, c in -5000..5000i64) {
+        let is_negative = |x: i128| (a as i128) * x * x + (b as i128) * x + (c as i128) < 0;
+        let negatives: Vec<i128> = (-2000..=2000).filter(|&x| is_negative(x)).collect();
+        let expected = negatives.first().zip(negatives.last()).map(|(&low, &high)| low..=high);
+
+        prop_assert_eq!(quadratic_negative_range(a, b, c), expected);
+    }
+
+    #[test]
+    fn quadratic_negative_range_is_exact_for_large_races(time in 0..i64::MAX / 2, distance in any::<i64>()) {
+        // Holding the button for x of the race's time beats the distance when x^2 - time*x + distance < 0
+        let distance = distance.rem_euclid(time.max(1)).saturating_mul(time / 4);
+        let beats = |x: i128| x * (time as i128 - x) > distance as i128;
+
+        if let Some(range) = quadratic_negative_range(1, -time, distance) {
+            let (low, high) = (*range.start(), *range.end());
+            prop_assert!(beats(low) && beats(high));
+            prop_assert!(!beats(low - 1) && !beats(high + 1));
+        } else {
+            prop_assert!(!beats(time as i128 / 2) && !beats((time as i128 + 1) / 2));
+        }
+    }
+
+    #[test]
+    fn interpolation_goes_through_every_point(points in prop::collection::btree_map(-50..50i64, -1000..1000i64, 1..8)) {
+        let points: Vec<(i64, i64)> = points.into_iter().collect();
+        let polynomial = Polynomial::interpolate(&points).unwrap();
+
+        prop_assert!(polynomial.degree().is_none_or(|degree| degree < points.len()));
+        for &(x, y) in points.iter() {
+            prop_assert_eq!(polynomial.eval(&ratio(x)), ratio(y));
+        }
+    }
+
+    #[test]
+    fn newton_and_lagrange_agree(points in prop::collection::btree_map(-50..50i64, -1000..1000i64, 1..8), x in -100..100i64) {
+        let points: Vec<(i64, i64)> = points.into_iter().collect();
+        let polynomial = Polynomial::interpolate(&points).unwrap();
+
+        prop_assert_eq!(polynomial.eval(&ratio(x)), lagrange_eval(&points, x).unwrap());
+    }
+
+    #[test]
+    fn interpolation_recovers_the_polynomial(coefficients in prop::collection::vec(-20..20i64, 1..6), start in -10..10i64) {
+        // Enough points on a polynomial pin it down exactly
+        let expected = Polynomial::new(coefficients.iter().map(|&x| ratio(x)).collect());
+        let points: Vec<(i64, i64)> = (start..start + coefficients.len() as i64 + 2)
+            .map(|x| {
+                let y = expected.eval(&ratio(x)).to_integer().try_into().unwrap();
+                (x, y)
+            })
+            .collect();
+
+        prop_assert_eq!(Polynomial::interpolate(&points).unwrap(), expected);
+    }
+}
+
+#[test]
+fn crt_rejects_incompatible_congruences() {
+    assert_eq!(crt([(1, 4), (2, 6)]), None);
+    assert_eq!(crt([(3, 4), (5, 6)]), Some((11, 12)));
+    assert_eq!(crt([(0, 0)]), None);
+}
+
+#[test]
+fn interpolation_rejects_repeated_xs() {
+    assert!(Polynomial::interpolate(&[(1, 2), (1, 3)]).is_err());
+    assert!(lagrange_eval(&[(1, 2), (1, 3)], 0).is_err());
+}
+
+#[test]
+fn quadratic_negative_range_handles_extreme_coefficients() {
+    // Checked in BigInts, since a*x^2 + b*x + c overflows an i128 for most of these
+    let is_negative = |(a, b, c): (i64, i64, i64), x: i128| {
+        let x = BigInt::from(x);
+        BigInt::from(a) * &x * &x + BigInt::from(b) * &x + BigInt::from(c) < BigInt::from(0)
+    };
+
+    for coefficients in [
+        (i64::MAX, i64::MIN, i64::MIN),
+        (i64::MAX, i64::MAX, i64::MIN),
+        (1, i64::MIN, i64::MIN),
+        (1, i64::MAX, i64::MAX),
+    ] {
+        let (a, b, c) = coefficients;
+        let range = quadratic_negative_range(a, b, c).unwrap();
+        let (low, high) = (*range.start(), *range.end());
+        assert!(is_negative(coefficients, low) && is_negative(coefficients, high));
+        assert!(!is_negative(coefficients, low - 1) && !is_negative(coefficients, high + 1));
+    }
+    assert_eq!(
+        quadratic_negative_range(i64::MAX, i64::MIN, i64::MIN),
+        Some(0..=1)
+    );
+    assert_eq!(quadratic_negative_range(i64::MAX, 0, i64::MAX), None);
+}
@@ -0,0 +1,98 @@
+/*
+Tests for the memoisation helpers, and for day 12's arrangement counting built on them,
+which is checked against trying every way of filling in the unknown springs.
+*/
+use proptest::prelude::*;
+
+use advent_of_code::solutions::y2023::day12::{count_arrangements, explain_arrangements};
+use advent_of_code::utils::memo::{memoize, Memo};
+
+fn brute_force_arrangements(springs: &[u8], groups: &[usize]) -> u64 {
+    let unknown: Vec<usize> = (0..springs.len()).filter(|&i| springs[i] == b'?').collect();
+    let mut count = 0;
+    for mask in 0..1u32 << unknown.len() {
+        let mut filled = springs.to_vec();
+        for (bit, &i) in unknown.iter().enumerate() {
+            filled[i] = if mask >> bit & 1 == 1 { b'#' } else { b'.' };
+        }
+        let runs: Vec<usize> = filled
+            .split(|&c| c == b'.')
+            .filter(|run| !run.is_empty())
+            .map(|run| run.len())
+            .collect();
+        count += (runs == groups) as u64;
+    }
+    count
+}
+
+#[test]
+fn recursion_goes_through_the_cache() {
+    let mut fibonacci = Memo::new(|fibonacci, n: u64| {
+        if n < 2 {
+            n
+        } else {
+            fibonacci(n - 1) + fibonacci(n - 2)
+        }
+    });
+    assert_eq!(fibonacci.get(90), 2880067194370816120);
+
+    // Each state is only worked out once
+    if let Some(stats) = fibonacci.stats() {
+        assert_eq!(stats.misses, 91);
+        fibonacci.get(90);
+        assert_eq!(stats.hits + 1, fibonacci.stats().unwrap().hits);
+    }
+}
+
+#[test]
+fn dense_and_hashed_caches_agree() {
+    let binomial = |binomial: &mut dyn FnMut([usize; 2]) -> u64, [n, k]: [usize; 2]| {
+        if k == 0 || k == n {
+            1
+        } else {
+            binomial([n - 1, k - 1]) + binomial([n - 1, k])
+        }
+    };
+    let mut dense = Memo::dense([61, 61], binomial);
+    assert_eq!(dense.get([60, 30]), 118264581564861424);
+    assert_eq!(memoize([60, 30], binomial), 118264581564861424);
+}
+
+#[test]
+#[should_panic(expected = "outside the table")]
+fn dense_cache_rejects_states_outside_the_table() {
+    Memo::dense([4], |_, [n]: [usize; 1]| n).get([4]);
+}
+
+#[test]
+fn counts_the_example_arrangements() {
+    assert_eq!(count_arrangements(b"???.###", &[1, 1, 3]), 1);
+    assert_eq!(count_arrangements(b"?###????????", &[3, 2, 1]), 10);
+    assert_eq!(count_arrangements(b"", &[]), 1);
+    assert_eq!(count_arrangements(b"#", &[]), 0);
+}
+
+#[test]
+fn explains_the_cache_use_for_each_row() {
+    let rows = explain_arrangements("???.### 1,1,3\n\n?###???????? 3,2,1\n", 5);
+    let lines: Vec<(usize, u64)> = rows
+        .iter()
+        .map(|row| (row.line, row.arrangements))
+        .collect();
+    assert_eq!(lines, vec![(1, 1), (3, 506250)]);
+
+    // Stats are only counted in debug builds
+    if cfg!(debug_assertions) {
+        assert!(rows.iter().all(|row| row.misses > 0 && row.hits > 0));
+    }
+}
+
+proptest! {
+    #[test]
+    fn arrangements_match_brute_force(springs in "[.#?]{0,14}", groups in prop::collection::vec(1..4usize, 0..5)) {
+        prop_assert_eq!(
+            count_arrangements(springs.as_bytes(), &groups),
+            brute_force_arrangements(springs.as_bytes(), &groups)
+        );
+    }
+}
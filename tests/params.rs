@@ -0,0 +1,78 @@
+/*
+Tests for solution parameters: every declared default should pass its own check, and values
+a solution can't read should be rejected when resolving rather than when the solution runs.
+*/
+use advent_of_code::helpers::{Param, Params};
+use advent_of_code::solutions::YEARS;
+
+fn declared(year: u32, day: u32) -> &'static [Param] {
+    let (_, days) = YEARS.iter().find(|(y, _)| *y == year).unwrap();
+    let (_, _, params) = days.iter().find(|(d, _, _)| *d == day).unwrap();
+    params
+}
+
+fn resolve(year: u32, day: u32, name: &str, value: &str) -> Result<Params, String> {
+    Params::resolve(declared(year, day), &Params::from(&[(name, value)][..]))
+}
+
+#[test]
+fn defaults_pass_their_checks() {
+    for (year, days) in YEARS.iter() {
+        for (day, _, params) in days.iter() {
+            for param in params.iter() {
+                let default = param.default.to_string();
+                assert_eq!(
+                    (param.parse)(&default),
+                    Ok(()),
+                    "{year} day {day} parameter '{}' has an invalid default",
+                    param.name
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn values_are_checked_as_the_type_the_solution_reads() {
+    let rejected = [
+        (2, "bag", "-1 red"),
+        (2, "bag", "12 red; 13 green"),
+        (6, "accel", "-1"),
+        (9, "max_depth", "-1"),
+        (11, "expansion", "-5"),
+        (11, "expansion", "0"),
+        (1, "vocabulary", "klingon"),
+        (1, "vocabulary", "ten=10"),
+        (3, "gear_symbol", "ab"),
+        (3, "gear_neighbours", "two"),
+        (3, "gear_aggregate", "median"),
+        (3, "render", "png"),
+    ];
+    for (day, name, value) in rejected {
+        let error = resolve(2023, day, name, value).unwrap_err();
+        assert!(error.contains(name), "{error}");
+    }
+
+    let accepted = [
+        (11, "expansion", "10"),
+        (2, "bag", "12 red, 1 purple"),
+        (2, "bag", ""),
+        (1, "vocabulary", "english,zero=0"),
+        (3, "gear_symbol", "any"),
+        (3, "gear_symbol", "#"),
+        (8, "start", "AAB"),
+    ];
+    for (day, name, value) in accepted {
+        let params = resolve(2023, day, name, value).unwrap();
+        assert_eq!(params.get::<String>(name), value);
+    }
+}
+
+#[test]
+fn unknown_parameters_are_rejected() {
+    let error = resolve(2023, 11, "expand", "10").unwrap_err();
+    assert!(error.contains("expected one of: expansion"), "{error}");
+
+    let error = resolve(2023, 4, "anything", "1").unwrap_err();
+    assert!(error.contains("none are available"), "{error}");
+}
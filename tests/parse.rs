@@ -0,0 +1,90 @@
+/*
+Tests for the input parsing helpers: which '-' signs count, out of range numbers giving
+errors rather than panics, and splitting into blocks against a line by line grouping.
+*/
+use itertools::Itertools;
+use proptest::prelude::*;
+
+use advent_of_code::utils::parse::{blocks, concat_digits, ints, key_values, values_for};
+
+/// Groups consecutive non-blank lines, for comparing against `blocks`.
+fn naive_blocks(input: &str) -> Vec<String> {
+    let groups = input.lines().group_by(|line| line.trim().is_empty());
+    groups
+        .into_iter()
+        .filter(|(blank, _)| !blank)
+        .map(|(_, lines)| lines.collect_vec().join("\n").trim_end().to_owned())
+        .collect()
+}
+
+proptest! {
+    #[test]
+    fn ints_reads_back_joined_numbers(values in prop::collection::vec(any::<i64>(), 0..10), sep in "[ ,:|]{1,3}") {
+        let line = values.iter().join(&sep);
+        prop_assert_eq!(ints::<i64>(&line), Ok(values));
+    }
+
+    #[test]
+    fn blocks_match_grouping_lines(lines in prop::collection::vec("[a-c1 ]{0,4}", 0..12)) {
+        let input = lines.join("\n");
+        let expected = naive_blocks(&input);
+        prop_assert_eq!(blocks(&input), expected.iter().map(String::as_str).collect_vec());
+    }
+}
+
+#[test]
+fn ints_reads_signs_only_where_they_start_a_number() {
+    assert_eq!(ints::<i64>("Card 3: -1 21 | 5"), Ok(vec![3, -1, 21, 5]));
+    assert_eq!(ints::<i64>("x=-5, y=--6"), Ok(vec![-5, -6]));
+    // A '-' after a letter or digit joins words or ranges
+    assert_eq!(ints::<i64>("seed-to-soil map 1-3"), Ok(vec![1, 3]));
+    assert_eq!(ints::<i64>("abc-12 7-"), Ok(vec![12, 7]));
+    assert_eq!(ints::<i64>("- 4 -x"), Ok(vec![4]));
+    assert_eq!(ints::<i64>("no numbers here"), Ok(vec![]));
+}
+
+#[test]
+fn ints_out_of_range_is_an_error() {
+    assert_eq!(ints::<u8>("255"), Ok(vec![255]));
+    let error = ints::<u8>("1 256").unwrap_err();
+    assert_eq!(error.to_string(), "Invalid integer '256' in '1 256'");
+
+    // Negative numbers can't be read as unsigned rather than being skipped
+    assert!(ints::<u32>("3 -1").is_err());
+    assert!(ints::<i64>("99999999999999999999").is_err());
+}
+
+#[test]
+fn concat_digits_joins_every_digit() {
+    assert_eq!(concat_digits::<u64>("Time:      7  15   30"), Ok(71530));
+    assert_eq!(concat_digits::<u64>("-1 2"), Ok(12));
+
+    let error = concat_digits::<u64>("Time:").unwrap_err();
+    assert_eq!(error.to_string(), "No digits found in 'Time:'");
+    assert!(concat_digits::<u8>("1 2 3 4").is_err());
+}
+
+#[test]
+fn blocks_ignore_extra_blank_lines() {
+    assert_eq!(blocks("a\nb\n\nc"), vec!["a\nb", "c"]);
+    assert_eq!(blocks("\n\n  \na\n\n\n\nb\nc\n \n"), vec!["a", "b\nc"]);
+    assert_eq!(blocks("a  \n\t\nb\r\n\r\nc\r\n"), vec!["a", "b", "c"]);
+    assert_eq!(blocks(""), Vec::<&str>::new());
+    assert_eq!(blocks("\n \n"), Vec::<&str>::new());
+}
+
+#[test]
+fn key_values_split_at_the_first_colon() {
+    assert_eq!(
+        key_values(" Game 1 : 3 red: x "),
+        Ok(("Game 1", "3 red: x"))
+    );
+    assert!(key_values("Game 1").is_err());
+
+    assert_eq!(values_for("Time: 7 15", "Time"), Ok("7 15"));
+    let error = values_for("Distance: 9", "Time").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Expected key 'Time' but found 'Distance' in 'Distance: 9'"
+    );
+}
@@ -0,0 +1,32 @@
+/*
+Tests for day 10's pipe loop, including stray pipes that connect to the start but aren't
+part of the loop.
+*/
+use advent_of_code::helpers::Params;
+use advent_of_code::solutions::y2023::day10::{
+    get_board, get_loop_path, get_solution_part1, get_solution_part2,
+};
+use advent_of_code::utils::Point;
+
+#[test]
+fn ignores_stray_pipes_next_to_the_start() {
+    // The '-' left of the start points at it, but leads nowhere
+    let input = ".....\n-S-7.\n.|.|.\n.L-J.\n.....";
+    let params = Params::default();
+    assert_eq!(get_solution_part1(input, &params), 4);
+    assert_eq!(get_solution_part2(input, &params), 1);
+
+    let path = get_loop_path(&get_board(input));
+    assert_eq!(path.nodes.len(), 9);
+    assert_eq!(path.nodes[0], Point::new(1, 1));
+    assert_eq!(path.nodes[8], Point::new(1, 1));
+    assert!(!path.nodes.contains(&Point::new(1, 0)));
+}
+
+#[test]
+fn ignores_stray_pipes_on_every_side() {
+    let input = "..|..\n.F7..\n-SJ|.\n.|...\n.....";
+    let params = Params::default();
+    assert_eq!(get_solution_part1(input, &params), 2);
+    assert_eq!(get_solution_part2(input, &params), 0);
+}
@@ -0,0 +1,79 @@
+/*
+Tests for day 6's race solver, checked against trying every press time for small races.
+*/
+use proptest::prelude::*;
+
+use advent_of_code::solutions::y2023::day6::Race;
+
+fn brute_force(race: &Race) -> Vec<u64> {
+    (0..=race.time)
+        .filter(|&press| race.accel * press * (race.time - press) > race.distance)
+        .collect()
+}
+
+proptest! {
+    #[test]
+    fn matches_brute_force(time in 0..500u64, distance in 0..70_000u64, accel in 0..5u64) {
+        let race = Race { time, distance, accel };
+        let expected = brute_force(&race);
+        let range = race.winning_press_times();
+
+        prop_assert_eq!(
+            range.map(|range| range.collect::<Vec<_>>()).unwrap_or_default(),
+            expected.clone()
+        );
+        prop_assert_eq!(race.count_winning_strategies(), expected.len() as u64);
+    }
+
+    #[test]
+    fn bounds_are_exact_for_large_races(time in any::<u64>(), distance in any::<u64>(), accel in 1..4u64) {
+        let race = Race { time, distance, accel };
+        match race.winning_press_times() {
+            Some(range) => {
+                prop_assert!(race.wins(*range.start()) && race.wins(*range.end()));
+                prop_assert!(*range.start() == 0 || !race.wins(range.start() - 1));
+                prop_assert!(*range.end() == time || !race.wins(range.end() + 1));
+            }
+            None => prop_assert!(!race.wins(time / 2) && !race.wins(time.div_ceil(2))),
+        }
+    }
+}
+
+#[test]
+fn record_that_cannot_be_beaten() {
+    // The best is holding for 5 ms of 10, travelling 25 mm
+    let race = Race {
+        time: 10,
+        distance: 25,
+        accel: 1,
+    };
+    assert_eq!(race.winning_press_times(), None);
+    assert_eq!(race.count_winning_strategies(), 0);
+
+    let race = Race {
+        time: 10,
+        distance: 24,
+        accel: 1,
+    };
+    assert_eq!(race.winning_press_times(), Some(5..=5));
+}
+
+#[test]
+fn precision_past_f64() {
+    // Only holding for exactly half the race wins, which f64 can't tell apart at this size
+    let half = 1u64 << 31;
+    let race = Race {
+        time: 2 * half,
+        distance: half * half - 1,
+        accel: 1,
+    };
+    assert_eq!(race.winning_press_times(), Some(half..=half));
+
+    // Holding for 1 ms of the longest race only ties the record
+    let race = Race {
+        time: u64::MAX,
+        distance: u64::MAX,
+        accel: 1,
+    };
+    assert_eq!(race.winning_press_times(), Some(2..=u64::MAX - 2));
+}
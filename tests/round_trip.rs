@@ -0,0 +1,196 @@
+/*
+Round-trip tests for the puzzle domain types: displaying a value and parsing it back
+should give the same value, and malformed input should give an error rather than panic.
+*/
+use itertools::Itertools;
+use proptest::prelude::*;
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+
+use advent_of_code::solutions::y2023::day2::{BallCount, Game, GameSet};
+use advent_of_code::solutions::y2023::day4::Card;
+use advent_of_code::solutions::y2023::day5::{Almanac, CategoryMap, Map};
+use advent_of_code::solutions::y2023::day6::{Race, Races};
+use advent_of_code::solutions::y2023::day7::{Hand, CARD_ORDER};
+use advent_of_code::solutions::y2023::day8::Node;
+
+fn assert_round_trip<T>(value: T) -> Result<(), TestCaseError>
+where
+    T: FromStr + Display + Debug + PartialEq,
+    T::Err: Debug,
+{
+    let text = value.to_string();
+    let parsed = text.parse::<T>();
+    prop_assert!(parsed.is_ok(), "failed to parse {text:?}: {parsed:?}");
+    prop_assert_eq!(parsed.unwrap(), value, "round trip through {:?}", text);
+    Ok(())
+}
+
+fn ball_count() -> impl Strategy<Value = BallCount> {
+    (0..100u32, "[a-z]{1,8}").prop_map(|(count, colour)| BallCount { colour, count })
+}
+
+fn game_set() -> impl Strategy<Value = GameSet> {
+    prop::collection::vec(ball_count(), 1..4).prop_map(|ball_counts| GameSet { ball_counts })
+}
+
+fn game() -> impl Strategy<Value = Game> {
+    (0..1000u32, prop::collection::vec(game_set(), 1..5))
+        .prop_map(|(game_id, game_sets)| Game { game_id, game_sets })
+}
+
+fn scratch_card() -> impl Strategy<Value = Card> {
+    let numbers = |max_len| prop::collection::vec(0..100u32, 0..max_len);
+    (0..1000u32, numbers(10), numbers(25)).prop_map(|(card_id, winning_numbers, my_numbers)| Card {
+        card_id,
+        winning_numbers,
+        my_numbers,
+    })
+}
+
+fn map() -> impl Strategy<Value = Map> {
+    // Any values, as long as neither range runs past u64::MAX
+    (any::<u64>(), any::<u64>(), any::<u64>()).prop_map(|(dest_start, src_start, length)| Map {
+        dest_start,
+        src_start,
+        length: length.min(u64::MAX - dest_start.max(src_start)),
+    })
+}
+
+fn category_map() -> impl Strategy<Value = CategoryMap> {
+    (
+        "[a-z]{1,12}",
+        "[a-z]{1,12}",
+        prop::collection::vec(map(), 0..6),
+    )
+        .prop_map(|(source, destination, maps)| CategoryMap {
+            source,
+            destination,
+            // Maps are kept sorted by their source start when parsed
+            maps: maps
+                .into_iter()
+                .sorted_by_key(|map| map.src_start)
+                .collect(),
+        })
+}
+
+fn almanac() -> impl Strategy<Value = Almanac> {
+    (
+        prop::collection::vec(any::<u64>(), 0..10),
+        prop::collection::vec(category_map(), 0..8),
+    )
+        .prop_map(|(seeds, category_maps)| Almanac {
+            seeds,
+            category_maps,
+        })
+}
+
+fn race() -> impl Strategy<Value = Race> {
+    (any::<u64>(), any::<u64>()).prop_map(|(time, distance)| Race {
+        time,
+        distance,
+        accel: 1,
+    })
+}
+
+fn hand() -> impl Strategy<Value = Hand> {
+    (
+        prop::collection::vec(prop::sample::select(CARD_ORDER.to_vec()), 5),
+        0..10_000u32,
+    )
+        .prop_map(|(cards, bid)| Hand::new(&cards.iter().collect::<String>(), bid, false))
+}
+
+fn node() -> impl Strategy<Value = Node> {
+    ("[A-Z0-9]{3}", "[A-Z0-9]{3}", "[A-Z0-9]{3}").prop_map(|(node, left, right)| Node {
+        node,
+        left,
+        right,
+    })
+}
+
+proptest! {
+    #[test]
+    fn game_round_trips(game in game()) {
+        assert_round_trip(game)?;
+    }
+
+    #[test]
+    fn scratch_card_round_trips(card in scratch_card()) {
+        assert_round_trip(card)?;
+    }
+
+    #[test]
+    fn map_round_trips(map in map()) {
+        assert_round_trip(map)?;
+    }
+
+    #[test]
+    fn category_map_round_trips(category_map in category_map()) {
+        assert_round_trip(category_map)?;
+    }
+
+    #[test]
+    fn almanac_round_trips(almanac in almanac()) {
+        assert_round_trip(almanac)?;
+    }
+
+    #[test]
+    fn races_round_trip(races in prop::collection::vec(race(), 0..6)) {
+        assert_round_trip(Races { races })?;
+    }
+
+    #[test]
+    fn race_round_trips(race in race()) {
+        assert_round_trip(race)?;
+    }
+
+    #[test]
+    fn hand_round_trips(hand in hand()) {
+        assert_round_trip(hand)?;
+    }
+
+    #[test]
+    fn node_round_trips(node in node()) {
+        assert_round_trip(node)?;
+    }
+}
+
+#[test]
+fn puzzle_syntax_round_trips() {
+    let game = "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red";
+    assert_eq!(game.parse::<Game>().unwrap().to_string(), game);
+
+    let card = "Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1";
+    assert_eq!(card.parse::<Card>().unwrap().to_string(), card);
+
+    let races = "Time:      7  15   30\nDistance:  9  40  200";
+    assert_eq!(races.parse::<Races>().unwrap().to_string(), races);
+
+    let hand = "KTJJT 220";
+    assert_eq!(hand.parse::<Hand>().unwrap().to_string(), hand);
+    // Playing Jacks as Jokers changes how the hand ranks, not how it's written
+    let jokers = hand.parse::<Hand>().unwrap().with_jokers();
+    assert_eq!(jokers.to_string(), hand);
+
+    let node = "AAA = (BBB, CCC)";
+    assert_eq!(node.parse::<Node>().unwrap().to_string(), node);
+}
+
+#[test]
+fn malformed_input_is_an_error() {
+    assert!("Game x: 3 blue".parse::<Game>().is_err());
+    assert!("Game 1: 3 blue, red".parse::<Game>().is_err());
+    assert!("Card 1: 41 48 83 86 17".parse::<Card>().is_err());
+    assert!("50 98".parse::<Map>().is_err());
+    assert!("50 -98 2".parse::<Map>().is_err());
+    assert!("0 18446744073709551615 2".parse::<Map>().is_err());
+    assert!("seed-to-soil:\n50 98 2".parse::<CategoryMap>().is_err());
+    assert!("Time: 7 15\nDistance: 9".parse::<Races>().is_err());
+    assert!("Time: 7 15\nDistance: 9 40".parse::<Race>().is_err());
+    assert!("32T3 765".parse::<Hand>().is_err());
+    assert!("32T3Z 765".parse::<Hand>().is_err());
+    assert!("XXXX1 5".parse::<Hand>().is_err());
+    assert!("32T31 765".parse::<Hand>().is_err());
+    assert!("AAA = BBB, CCC".parse::<Node>().is_err());
+}
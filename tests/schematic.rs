@@ -0,0 +1,123 @@
+/*
+Tests for day 3's schematic graph, checked against comparing every symbol with every number.
+*/
+use proptest::prelude::*;
+
+use advent_of_code::solutions::y2023::day3::render::{annotate, render, CellKind, RenderFormat};
+use advent_of_code::solutions::y2023::day3::{Aggregate, GearRule, Number, Schematic, Symbol};
+
+const GEARS: GearRule = GearRule {
+    symbol: Some('*'),
+    neighbours: 2,
+    aggregate: Aggregate::Product,
+};
+
+// Drops the ANSI colour codes, leaving the text that was coloured
+fn strip_ansi(text: &str) -> String {
+    let mut stripped = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            chars.by_ref().find(|&c| c == 'm');
+        } else {
+            stripped.push(c);
+        }
+    }
+    stripped
+}
+
+fn is_adjacent(symbol: &Symbol, number: &Number) -> bool {
+    symbol.row_idx.abs_diff(number.row_idx) <= 1
+        && symbol.col_idx + 1 >= number.col_start_idx
+        && symbol.col_idx <= number.col_end_idx + 1
+}
+
+fn schematic_text() -> impl Strategy<Value = String> {
+    (1..8usize, 1..8usize).prop_flat_map(|(n_rows, n_cols)| {
+        prop::collection::vec("[.0-9*#+]", n_rows * n_cols).prop_map(move |cells| {
+            cells
+                .chunks(n_cols)
+                .map(|row| row.concat())
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+    })
+}
+
+#[test]
+fn gear_rules_are_configurable() {
+    let schematic: Schematic = "467..114..\n...*......\n..35..633.\n......#...\n617*......"
+        .parse()
+        .unwrap();
+    let rule = |symbol, neighbours, aggregate| GearRule {
+        symbol,
+        neighbours,
+        aggregate,
+    };
+
+    assert_eq!(
+        schematic.gear_ratios(&rule(Some('*'), 2, Aggregate::Product)),
+        vec![16345]
+    );
+    assert_eq!(
+        schematic.gear_ratios(&rule(Some('*'), 2, Aggregate::Sum)),
+        vec![502]
+    );
+    assert_eq!(
+        schematic.gear_ratios(&rule(None, 1, Aggregate::Max)),
+        vec![633, 617]
+    );
+    assert!("median".parse::<Aggregate>().is_err());
+}
+
+#[test]
+fn drawing_picks_out_parts_loose_numbers_and_gears() {
+    let schematic: Schematic = "467..114..\n...*......\n..35.&633.".parse().unwrap();
+    let kinds = annotate(&schematic, &GEARS);
+
+    assert_eq!(kinds[(0, 0)], CellKind::PartNumber);
+    assert_eq!(kinds[(0, 5)], CellKind::LooseNumber);
+    assert_eq!(kinds[(1, 3)], CellKind::Gear);
+    assert_eq!(kinds[(2, 5)], CellKind::Symbol);
+    assert_eq!(kinds[(2, 6)], CellKind::PartNumber);
+    assert_eq!(kinds[(1, 0)], CellKind::Empty);
+
+    let html = render(&schematic, &GEARS, RenderFormat::Html);
+    assert!(html.contains("<span class=\"loose\" style=\"color:#d03030\">114</span>"));
+    assert!(html.contains("&amp;") && !html.contains(".&6"));
+    let svg = render(&schematic, &GEARS, RenderFormat::Svg);
+    assert_eq!(svg.matches("class=\"gear\"").count(), 1);
+}
+
+proptest! {
+    #[test]
+    fn adjacency_matches_brute_force(text in schematic_text()) {
+        let schematic: Schematic = text.parse().unwrap();
+
+        for (i, symbol) in schematic.symbols.iter().enumerate() {
+            let expected: Vec<usize> = (0..schematic.numbers.len())
+                .filter(|&j| is_adjacent(symbol, &schematic.numbers[j]))
+                .collect();
+            prop_assert_eq!(&symbol.numbers, &expected);
+            for &j in expected.iter() {
+                prop_assert!(schematic.numbers[j].symbols.contains(&i));
+            }
+        }
+
+        let expected_parts: Vec<u64> = schematic
+            .numbers
+            .iter()
+            .filter(|number| schematic.symbols.iter().any(|symbol| is_adjacent(symbol, number)))
+            .map(|number| number.value)
+            .collect();
+        let parts: Vec<u64> = schematic.part_numbers().map(|number| number.value).collect();
+        prop_assert_eq!(parts, expected_parts);
+    }
+
+    #[test]
+    fn ansi_drawing_keeps_the_schematic_text(text in schematic_text()) {
+        let schematic: Schematic = text.parse().unwrap();
+        let drawing = render(&schematic, &GEARS, RenderFormat::Ansi);
+        prop_assert_eq!(strip_ansi(&drawing), format!("{text}\n"));
+    }
+}
@@ -0,0 +1,46 @@
+/*
+Tests for running a part under a timeout: results should come back as they are, slow parts
+should give up, and panics should reach the caller as if there were no timeout.
+*/
+use std::panic;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use advent_of_code::helpers::run_with_timeout;
+
+#[test]
+fn returns_the_result_without_a_timeout() {
+    assert_eq!(run_with_timeout(|| 6 * 7, None), Some(42));
+}
+
+#[test]
+fn returns_the_result_within_the_timeout() {
+    let result = run_with_timeout(|| 6 * 7, Some(Duration::from_secs(10)));
+    assert_eq!(result, Some(42));
+}
+
+#[test]
+fn gives_up_once_the_timeout_has_passed() {
+    let start = Instant::now();
+    let result = run_with_timeout(
+        || {
+            thread::sleep(Duration::from_secs(10));
+            42
+        },
+        Some(Duration::from_millis(50)),
+    );
+    assert_eq!(result, None);
+    // The slow part is left running in the background rather than waited for
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+#[test]
+fn passes_on_panics() {
+    for timeout in [None, Some(Duration::from_secs(10))] {
+        let result = panic::catch_unwind(|| {
+            run_with_timeout(|| -> u64 { panic!("example failed") }, timeout)
+        });
+        let panic = result.unwrap_err();
+        assert_eq!(panic.downcast_ref::<&str>(), Some(&"example failed"));
+    }
+}